@@ -0,0 +1,94 @@
+use crate::AMDGPU::{DeviceHandle, DpmLevel};
+use std::fmt;
+use std::path::PathBuf;
+
+/// GPU/memory activity and the current clocks, each `None` if not supported
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ActivityInfo {
+    /// `gpu_busy_percent`, %
+    pub gpu_busy_percent: Option<u8>,
+    /// `mem_busy_percent`, %
+    pub mem_busy_percent: Option<u8>,
+    /// Active level of `pp_dpm_sclk`, MHz
+    pub sclk: Option<u32>,
+    /// Active level of `pp_dpm_mclk`, MHz
+    pub mclk: Option<u32>,
+    /// `mem_info_vram_vendor` (e.g. "samsung", "hynix")
+    pub vram_vendor: Option<String>,
+}
+
+impl ActivityInfo {
+    pub fn get_from_sysfs<P: Into<PathBuf>>(sysfs_path: P) -> Self {
+        let path = sysfs_path.into();
+        let [gpu_busy_percent, mem_busy_percent, pp_dpm_sclk, pp_dpm_mclk] = [
+            "gpu_busy_percent",
+            "mem_busy_percent",
+            "pp_dpm_sclk",
+            "pp_dpm_mclk",
+        ].map(|name| std::fs::read_to_string(path.join(name)).ok());
+        let vram_vendor = DeviceHandle::get_vram_vendor_from_sysfs(&path);
+
+        Self::parse(
+            gpu_busy_percent.as_deref(),
+            mem_busy_percent.as_deref(),
+            pp_dpm_sclk.as_deref(),
+            pp_dpm_mclk.as_deref(),
+            vram_vendor.as_deref(),
+        )
+    }
+
+    /// Parse the contents of `gpu_busy_percent`, `mem_busy_percent`, `pp_dpm_sclk`, `pp_dpm_mclk`
+    /// and `mem_info_vram_vendor`, `None` for a missing file
+    pub fn parse(
+        gpu_busy_percent: Option<&str>,
+        mem_busy_percent: Option<&str>,
+        pp_dpm_sclk: Option<&str>,
+        pp_dpm_mclk: Option<&str>,
+        vram_vendor: Option<&str>,
+    ) -> Self {
+        let [gpu_busy_percent, mem_busy_percent] = [gpu_busy_percent, mem_busy_percent]
+            .map(|s| s?.trim_end().parse::<u32>().ok().map(|v| v.min(100) as u8));
+        let [sclk, mclk] = [pp_dpm_sclk, pp_dpm_mclk].map(|s| DpmLevel::active_clock(s?));
+        let vram_vendor = vram_vendor.map(|s| s.trim_end().to_string()).filter(|s| !s.is_empty());
+
+        Self { gpu_busy_percent, mem_busy_percent, sclk, mclk, vram_vendor }
+    }
+}
+
+impl DeviceHandle {
+    /// Read [ActivityInfo] with one sysfs path lookup
+    pub fn get_activity_info(&self) -> ActivityInfo {
+        let Ok(sysfs_path) = self.get_sysfs_path() else { return ActivityInfo::default() };
+
+        ActivityInfo::get_from_sysfs(sysfs_path)
+    }
+}
+
+/// e.g. "GPU 72% / MEM 40%", "GPU 72% / MEM N/A"
+impl fmt::Display for ActivityInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let percent = |v: Option<u8>| v.map_or("N/A".to_string(), |v| format!("{v}%"));
+
+        write!(f, "GPU {} / MEM {}", percent(self.gpu_busy_percent), percent(self.mem_busy_percent))
+    }
+}
+
+#[test]
+fn test_activity_info_parse() {
+    let info = ActivityInfo::parse(
+        Some("72\n"),
+        None,
+        Some("0: 500Mhz \n1: 2500Mhz *\n"),
+        None,
+        Some("samsung\n"),
+    );
+
+    assert_eq!(info, ActivityInfo {
+        gpu_busy_percent: Some(72),
+        mem_busy_percent: None,
+        sclk: Some(2500),
+        mclk: None,
+        vram_vendor: Some("samsung".to_string()),
+    });
+    assert_eq!(info.to_string(), "GPU 72% / MEM N/A");
+}
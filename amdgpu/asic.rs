@@ -129,6 +129,7 @@ pub enum ASIC_NAME {
     CHIP_NAVI10, /* Radeon 5600, 5700 */
     CHIP_NAVI12, /* Radeon Pro 5600M */
     CHIP_NAVI14, /* Radeon 5300, 5500 */
+    CHIP_GFX1013, /* AMD BC-250, Cyan Skillfish */
     /* GFX10.3 (RDNA 2) */
     CHIP_NAVI21,    /* Radeon 6800, 6900 */
     CHIP_NAVI22,    /* Radeon 6700 */
@@ -142,106 +143,216 @@ pub enum ASIC_NAME {
     CHIP_GFX1102, /* Navi33 */
     CHIP_GFX1103_R1, /* Phoenix */
     CHIP_GFX1103_R2, /* Phoenix? */
-    CHIP_GFX1150,
-    CHIP_GFX1151,
+    /* GFX11.5 (RDNA 3.5) */
+    CHIP_GFX1150, /* Strix Point */
+    CHIP_GFX1151, /* Strix Halo */
+    CHIP_GFX1152, /* Krackan Point */
+    CHIP_GFX1153,
 }
 
 impl ASIC_NAME {
+    /// All variants except [ASIC_NAME::CHIP_UNKNOWN], in declaration order
+    pub const ALL: &'static [Self] = &[
+        Self::CHIP_R300,
+        Self::CHIP_R350,
+        Self::CHIP_RV350,
+        Self::CHIP_RV370,
+        Self::CHIP_RV380,
+        Self::CHIP_RS400,
+        Self::CHIP_RC410,
+        Self::CHIP_RS480,
+        Self::CHIP_R420,
+        Self::CHIP_R423,
+        Self::CHIP_R430,
+        Self::CHIP_R480,
+        Self::CHIP_R481,
+        Self::CHIP_RV410,
+        Self::CHIP_RS600,
+        Self::CHIP_RS690,
+        Self::CHIP_RS740,
+        Self::CHIP_RV515,
+        Self::CHIP_R520,
+        Self::CHIP_RV530,
+        Self::CHIP_R580,
+        Self::CHIP_RV560,
+        Self::CHIP_RV570,
+        Self::CHIP_R600,
+        Self::CHIP_RV610,
+        Self::CHIP_RV630,
+        Self::CHIP_RV670,
+        Self::CHIP_RV620,
+        Self::CHIP_RV635,
+        Self::CHIP_RS780,
+        Self::CHIP_RS880,
+        Self::CHIP_RV770,
+        Self::CHIP_RV730,
+        Self::CHIP_RV710,
+        Self::CHIP_RV740,
+        Self::CHIP_CEDAR,
+        Self::CHIP_REDWOOD,
+        Self::CHIP_JUNIPER,
+        Self::CHIP_CYPRESS,
+        Self::CHIP_HEMLOCK,
+        Self::CHIP_PALM,
+        Self::CHIP_SUMO,
+        Self::CHIP_SUMO2,
+        Self::CHIP_BARTS,
+        Self::CHIP_TURKS,
+        Self::CHIP_CAICOS,
+        Self::CHIP_CAYMAN,
+        Self::CHIP_ARUBA,
+        Self::CHIP_TAHITI,
+        Self::CHIP_PITCAIRN,
+        Self::CHIP_VERDE,
+        Self::CHIP_OLAND,
+        Self::CHIP_HAINAN,
+        Self::CHIP_BONAIRE,
+        Self::CHIP_KAVERI,
+        Self::CHIP_LIVERPOOL,
+        Self::CHIP_GLADIUS,
+        Self::CHIP_KABINI,
+        Self::CHIP_HAWAII,
+        Self::CHIP_TONGA,
+        Self::CHIP_ICELAND,
+        Self::CHIP_CARRIZO,
+        Self::CHIP_FIJI,
+        Self::CHIP_STONEY,
+        Self::CHIP_POLARIS10,
+        Self::CHIP_POLARIS11,
+        Self::CHIP_POLARIS12,
+        Self::CHIP_VEGAM,
+        Self::CHIP_VEGA10,
+        Self::CHIP_VEGA12,
+        Self::CHIP_VEGA20,
+        Self::CHIP_RAVEN,
+        Self::CHIP_RAVEN2,
+        Self::CHIP_RENOIR,
+        Self::CHIP_ARCTURUS,
+        Self::CHIP_ALDEBARAN,
+        Self::CHIP_GFX940,
+        Self::CHIP_NAVI10,
+        Self::CHIP_NAVI12,
+        Self::CHIP_NAVI14,
+        Self::CHIP_GFX1013,
+        Self::CHIP_NAVI21,
+        Self::CHIP_NAVI22,
+        Self::CHIP_VANGOGH,
+        Self::CHIP_NAVI23,
+        Self::CHIP_NAVI24,
+        Self::CHIP_REMBRANDT,
+        Self::CHIP_GFX1036,
+        Self::CHIP_GFX1100,
+        Self::CHIP_GFX1101,
+        Self::CHIP_GFX1102,
+        Self::CHIP_GFX1103_R1,
+        Self::CHIP_GFX1103_R2,
+        Self::CHIP_GFX1150,
+        Self::CHIP_GFX1151,
+        Self::CHIP_GFX1152,
+        Self::CHIP_GFX1153,
+    ];
+
+    /// All variants except [ASIC_NAME::CHIP_UNKNOWN], in declaration order
+    pub const fn all() -> &'static [Self] {
+        Self::ALL
+    }
+
     /// Get the ASIC name from [FAMILY_NAME] and `chip_external_rev`
     pub fn get(family: FAMILY_NAME, chip_external_rev: u32) -> Self {
+        match family {
+            FAMILY_NAME::VGH => Self::CHIP_VANGOGH,
+            FAMILY_NAME::YC => Self::CHIP_REMBRANDT,
+            FAMILY_NAME::GC_10_3_6 |
+            FAMILY_NAME::GC_10_3_7 => Self::CHIP_GFX1036,
+            _ => Self::rev_table(family)
+                .iter()
+                .find(|(min, max, _)| (*min..=*max).contains(&chip_external_rev))
+                .map(|(_, _, asic)| *asic)
+                .unwrap_or(Self::CHIP_UNKNOWN),
+        }
+    }
+
+    /// `(min, max, ASIC)` ranges of `chip_external_rev` (inclusive) for each [FAMILY_NAME].  
+    /// The ranges of a family are sorted and must not overlap.
+    const fn rev_table(family: FAMILY_NAME) -> &'static [(u32, u32, Self)] {
         /*
             https://gitlab.freedesktop.org/mesa/mesa/blob/main/src/amd/addrlib/src/amdgpu_asic_addr.h
             Commit: fd3451babd6cded6794561d74c8919576ba1f97d
         */
-        let rev = chip_external_rev;
-
         match family {
-            FAMILY_NAME::SI => match rev {
-                0x05..=0x14 => Self::CHIP_TAHITI,
-                0x15..=0x28 => Self::CHIP_PITCAIRN,
-                0x29..=0x3B => Self::CHIP_VERDE,
-                0x3C..=0x45 => Self::CHIP_OLAND,
-                0x46..=0xFF => Self::CHIP_HAINAN,
-                _ => Self::CHIP_UNKNOWN,
-            },
-            FAMILY_NAME::CI => match rev {
-                0x14..=0x27 => Self::CHIP_BONAIRE,
-                0x28..=0x3B => Self::CHIP_HAWAII,
-                _ => Self::CHIP_UNKNOWN,
-            },
-            FAMILY_NAME::KV => {
-                match rev {
-                    /* Spectre, Spooky */
-                    0x01..=0x40 | 0x41..=0x42 => Self::CHIP_KAVERI,
-                    /* Liverpool */
-                    0x42..=0x61 => Self::CHIP_LIVERPOOL,
-                    /* Gladius */
-                    0x62..=0x71 => Self::CHIP_GLADIUS,
-                    /* Kalindi, Godavari */
-                    0x81..=0xA0 | 0xA1..=0xFF => Self::CHIP_KABINI,
-                    _ => Self::CHIP_UNKNOWN,
-                }
-            }
-            FAMILY_NAME::VI => match rev {
-                0x01..=0x13 => Self::CHIP_ICELAND,
-                0x14..=0x3B => Self::CHIP_TONGA,
-                0x3C..=0x4F => Self::CHIP_FIJI,
-                0x50..=0x59 => Self::CHIP_POLARIS10,
-                0x5A..=0x63 => Self::CHIP_POLARIS11,
-                0x64..=0x6D => Self::CHIP_POLARIS12,
-                0x6E..=0xFF => Self::CHIP_VEGAM,
-                _ => Self::CHIP_UNKNOWN,
-            },
-            FAMILY_NAME::AI => match rev {
-                0x01..=0x13 => Self::CHIP_VEGA10,
-                0x14..=0x27 => Self::CHIP_VEGA12,
-                0x28..=0x31 => Self::CHIP_VEGA20,
-                0x32..=0x3B => Self::CHIP_ARCTURUS,
-                0x3C..=0x45 => Self::CHIP_ALDEBARAN,
+            FAMILY_NAME::SI => &[
+                (0x05, 0x14, Self::CHIP_TAHITI),
+                (0x15, 0x28, Self::CHIP_PITCAIRN),
+                (0x29, 0x3B, Self::CHIP_VERDE),
+                (0x3C, 0x45, Self::CHIP_OLAND),
+                (0x46, 0xFF, Self::CHIP_HAINAN),
+            ],
+            FAMILY_NAME::CI => &[
+                (0x14, 0x27, Self::CHIP_BONAIRE),
+                (0x28, 0x3B, Self::CHIP_HAWAII),
+            ],
+            FAMILY_NAME::KV => &[
+                /* Spectre, Spooky */
+                (0x01, 0x41, Self::CHIP_KAVERI),
+                /* Liverpool */
+                (0x42, 0x61, Self::CHIP_LIVERPOOL),
+                /* Gladius */
+                (0x62, 0x71, Self::CHIP_GLADIUS),
+                /* Kalindi, Godavari */
+                (0x81, 0xFF, Self::CHIP_KABINI),
+            ],
+            FAMILY_NAME::VI => &[
+                (0x01, 0x13, Self::CHIP_ICELAND),
+                (0x14, 0x3B, Self::CHIP_TONGA),
+                (0x3C, 0x4F, Self::CHIP_FIJI),
+                (0x50, 0x59, Self::CHIP_POLARIS10),
+                (0x5A, 0x63, Self::CHIP_POLARIS11),
+                (0x64, 0x6D, Self::CHIP_POLARIS12),
+                (0x6E, 0xFF, Self::CHIP_VEGAM),
+            ],
+            FAMILY_NAME::AI => &[
+                (0x01, 0x13, Self::CHIP_VEGA10),
+                (0x14, 0x27, Self::CHIP_VEGA12),
+                (0x28, 0x31, Self::CHIP_VEGA20),
+                (0x32, 0x3B, Self::CHIP_ARCTURUS),
+                (0x3C, 0x45, Self::CHIP_ALDEBARAN),
                 /*
                     ref: drivers/gpu/drm/amd/amdgpu/soc15.c
                     ref: https://gitlab.freedesktop.org/mesa/mesa/-/blob/main/src/amd/common/ac_gpu_info.c
                 */
-                0x46..=0xFF => Self::CHIP_GFX940,
-                _ => Self::CHIP_UNKNOWN,
-            },
-            FAMILY_NAME::RV => match rev {
-                0x01..=0x80 => Self::CHIP_RAVEN,
-                0x81..=0x90 => Self::CHIP_RAVEN2,
-                0x91..=0xFF => Self::CHIP_RENOIR,
-                _ => Self::CHIP_UNKNOWN,
-            },
-            FAMILY_NAME::NV => match rev {
-                0x01..=0x09 => Self::CHIP_NAVI10,
-                0x0A..=0x13 => Self::CHIP_NAVI12,
-                0x14..=0x27 => Self::CHIP_NAVI14,
-                0x28..=0x31 => Self::CHIP_NAVI21,
-                0x32..=0x3B => Self::CHIP_NAVI22,
-                0x3C..=0x45 => Self::CHIP_NAVI23,
-                0x46..=0x4F => Self::CHIP_NAVI24,
-                _ => Self::CHIP_UNKNOWN,
-            },
-            FAMILY_NAME::VGH => Self::CHIP_VANGOGH,
-            FAMILY_NAME::GC_11_0_0 => match rev {
-                0x01..=0x09 => Self::CHIP_GFX1100,
-                0x10..=0x19 => Self::CHIP_GFX1102,
-                0x20..=0xFF => Self::CHIP_GFX1101,
-                _ => Self::CHIP_UNKNOWN,
-            },
-            FAMILY_NAME::YC => Self::CHIP_REMBRANDT,
-            FAMILY_NAME::GC_11_0_1 => match rev {
-                0x01..=0x79 => Self::CHIP_GFX1103_R1,
-                0x80..=0xFF => Self::CHIP_GFX1103_R2,
-                _ => Self::CHIP_UNKNOWN,
-            },
-            FAMILY_NAME::GC_10_3_6 |
-            FAMILY_NAME::GC_10_3_7 => Self::CHIP_GFX1036,
-            FAMILY_NAME::GC_11_5_0 => match rev {
-                0x01..=0x39 => Self::CHIP_GFX1150,
-                0xC0..=0xFF => Self::CHIP_GFX1151,
-                _ => Self::CHIP_UNKNOWN,
-            },
-            _ => Self::CHIP_UNKNOWN,
+                (0x46, 0xFF, Self::CHIP_GFX940),
+            ],
+            FAMILY_NAME::RV => &[
+                (0x01, 0x80, Self::CHIP_RAVEN),
+                (0x81, 0x90, Self::CHIP_RAVEN2),
+                (0x91, 0xFF, Self::CHIP_RENOIR),
+            ],
+            FAMILY_NAME::NV => &[
+                (0x01, 0x09, Self::CHIP_NAVI10),
+                (0x0A, 0x13, Self::CHIP_NAVI12),
+                (0x14, 0x27, Self::CHIP_NAVI14),
+                (0x28, 0x31, Self::CHIP_NAVI21),
+                (0x32, 0x3B, Self::CHIP_NAVI22),
+                (0x3C, 0x45, Self::CHIP_NAVI23),
+                (0x46, 0x4F, Self::CHIP_NAVI24),
+                (0x84, 0x84, Self::CHIP_GFX1013),
+            ],
+            FAMILY_NAME::GC_11_0_0 => &[
+                (0x01, 0x09, Self::CHIP_GFX1100),
+                (0x10, 0x19, Self::CHIP_GFX1102),
+                (0x20, 0xFF, Self::CHIP_GFX1101),
+            ],
+            FAMILY_NAME::GC_11_0_1 => &[
+                (0x01, 0x79, Self::CHIP_GFX1103_R1),
+                (0x80, 0xFF, Self::CHIP_GFX1103_R2),
+            ],
+            FAMILY_NAME::GC_11_5_0 => &[
+                (0x01, 0x39, Self::CHIP_GFX1150),
+                (0x40, 0x4F, Self::CHIP_GFX1152),
+                (0x50, 0x5F, Self::CHIP_GFX1153),
+                (0xC0, 0xFF, Self::CHIP_GFX1151),
+            ],
+            _ => &[],
         }
     }
 
@@ -250,11 +361,55 @@ impl ASIC_NAME {
         CHIP_CLASS::from(*self)
     }
 
+    /// Architecture generation, e.g. "RDNA 3".  
+    /// Unlike [CHIP_CLASS::marketing_generation], the CDNA ASICs of GFX9 are labeled "CDNA 1/2/3".
+    pub fn marketing_generation(&self) -> &'static str {
+        match self {
+            Self::CHIP_ARCTURUS => "CDNA 1",
+            Self::CHIP_ALDEBARAN => "CDNA 2",
+            Self::CHIP_GFX940 => "CDNA 3",
+            _ => self.chip_class().marketing_generation(),
+        }
+    }
+
     /// Check if ASIC has RB+ (RenderBackendPlus)
     fn has_rbplus(&self) -> bool {
         *self == Self::CHIP_STONEY || *self >= Self::CHIP_VEGA10
     }
 
+    /// Check if ASIC is an APU (GPU on the same die/package as the CPU).  
+    /// Chipset-integrated GPUs (`RS*`) are not APUs.
+    /// [ASIC_NAME::CHIP_GFX940] returns `false` since MI300A is an APU but MI300X is not,
+    /// use the `AMDGPU_IDS_FLAGS_FUSION` bit of `ids_flags` for the runtime answer.
+    pub fn is_apu(&self) -> bool {
+        matches!(
+            self,
+            Self::CHIP_PALM |
+            Self::CHIP_SUMO |
+            Self::CHIP_SUMO2 |
+            Self::CHIP_ARUBA |
+            Self::CHIP_KAVERI |
+            Self::CHIP_LIVERPOOL |
+            Self::CHIP_GLADIUS |
+            Self::CHIP_KABINI |
+            Self::CHIP_CARRIZO |
+            Self::CHIP_STONEY |
+            Self::CHIP_RAVEN |
+            Self::CHIP_RAVEN2 |
+            Self::CHIP_RENOIR |
+            Self::CHIP_GFX1013 |
+            Self::CHIP_VANGOGH |
+            Self::CHIP_REMBRANDT |
+            Self::CHIP_GFX1036 |
+            Self::CHIP_GFX1103_R1 |
+            Self::CHIP_GFX1103_R2 |
+            Self::CHIP_GFX1150 |
+            Self::CHIP_GFX1151 |
+            Self::CHIP_GFX1152 |
+            Self::CHIP_GFX1153
+        )
+    }
+
     /// Check if RB+ is allowed
     pub fn rbplus_allowed(&self) -> bool {
         self.has_rbplus()
@@ -276,7 +431,7 @@ impl ASIC_NAME {
         *self == Self::CHIP_ARCTURUS
             || *self == Self::CHIP_ALDEBARAN
             || *self == Self::CHIP_VEGA20
-            || *self >= Self::CHIP_NAVI12
+            || (*self >= Self::CHIP_NAVI12 && *self != Self::CHIP_GFX1013)
     }
 
     pub fn max_wave64_per_simd(&self) -> u8 {
@@ -291,6 +446,21 @@ impl ASIC_NAME {
         }
     }
 
+    /// Check if wave32 is supported (RDNA/GFX10 and later)
+    pub fn supports_wave32(&self) -> bool {
+        *self >= Self::CHIP_NAVI10
+    }
+
+    /// Native wavefront size of the SIMD units.
+    /// `32` for RDNA (wave64 is still available, executed over two cycles), `64` for GCN/CDNA.
+    pub fn native_wave_size(&self) -> u32 {
+        if self.supports_wave32() {
+            32
+        } else {
+            64
+        }
+    }
+
     /// Number of SIMD units per CU
     pub fn num_simd_per_cu(&self) -> u8 {
         if *self >= Self::CHIP_NAVI10 {
@@ -378,8 +548,72 @@ impl ASIC_NAME {
         }
     }
 
+    /// (shader engines, shader arrays per SE) of the full chip
+    fn topology(&self) -> Option<(u32, u32)> {
+        Some(match self {
+            Self::CHIP_TAHITI |
+            Self::CHIP_PITCAIRN => (2, 2),
+            Self::CHIP_VERDE => (1, 2),
+            Self::CHIP_OLAND |
+            Self::CHIP_HAINAN => (1, 1),
+            Self::CHIP_BONAIRE |
+            Self::CHIP_LIVERPOOL => (2, 1),
+            Self::CHIP_HAWAII |
+            Self::CHIP_GLADIUS => (4, 1),
+            Self::CHIP_KAVERI |
+            Self::CHIP_KABINI => (1, 1),
+            Self::CHIP_TONGA |
+            Self::CHIP_FIJI |
+            Self::CHIP_POLARIS10 |
+            Self::CHIP_VEGAM => (4, 1),
+            Self::CHIP_POLARIS11 |
+            Self::CHIP_POLARIS12 => (2, 1),
+            Self::CHIP_ICELAND |
+            Self::CHIP_CARRIZO |
+            Self::CHIP_STONEY => (1, 1),
+            Self::CHIP_VEGA10 |
+            Self::CHIP_VEGA12 |
+            Self::CHIP_VEGA20 => (4, 1),
+            Self::CHIP_RAVEN |
+            Self::CHIP_RAVEN2 |
+            Self::CHIP_RENOIR => (1, 1),
+            Self::CHIP_ARCTURUS |
+            Self::CHIP_ALDEBARAN => (8, 1),
+            Self::CHIP_NAVI10 |
+            Self::CHIP_NAVI12 => (2, 2),
+            Self::CHIP_NAVI14 => (1, 2),
+            Self::CHIP_NAVI21 => (4, 2),
+            Self::CHIP_NAVI22 |
+            Self::CHIP_NAVI23 => (2, 2),
+            Self::CHIP_NAVI24 |
+            Self::CHIP_VANGOGH |
+            Self::CHIP_REMBRANDT => (1, 2),
+            Self::CHIP_GFX1036 => (1, 1),
+            Self::CHIP_GFX1100 => (6, 2),
+            Self::CHIP_GFX1101 => (3, 2),
+            Self::CHIP_GFX1102 => (2, 2),
+            Self::CHIP_GFX1103_R1 |
+            Self::CHIP_GFX1150 => (1, 2),
+            Self::CHIP_GFX1151 => (2, 2),
+            _ => return None,
+        })
+    }
+
+    /// Architectural number of shader engines of the full chip.  
+    /// Harvested configs may differ, prefer [GPU_INFO::max_se](crate::AMDGPU::GPU_INFO::max_se)
+    /// of the live device when available.
+    pub fn num_shader_engines(&self) -> Option<u32> {
+        self.topology().map(|(se, _)| se)
+    }
+
+    /// Architectural number of shader arrays per shader engine of the full chip.  
+    /// Harvested configs may differ, prefer [GPU_INFO::max_sa_per_se](crate::AMDGPU::GPU_INFO::max_sa_per_se)
+    /// of the live device when available.
+    pub fn num_shader_arrays_per_se(&self) -> Option<u32> {
+        self.topology().map(|(_, sa)| sa)
+    }
+
     /// Processor name for LLVM
-    #[cfg(feature = "std")]
     pub fn get_llvm_processor_name(&self, llvm_major_ver: usize) -> &str {
         match self {
             Self::CHIP_TAHITI => "tahiti",
@@ -410,6 +644,7 @@ impl ASIC_NAME {
             Self::CHIP_NAVI10 => "gfx1010",
             Self::CHIP_NAVI12 => "gfx1011",
             Self::CHIP_NAVI14 => "gfx1012",
+            Self::CHIP_GFX1013 => if llvm_major_ver >= 13 { "gfx1013" } else { "gfx1010" },
             Self::CHIP_NAVI21 => "gfx1030",
             Self::CHIP_NAVI22 => if llvm_major_ver >= 12 { "gfx1031" } else { "gfx1030" },
             Self::CHIP_NAVI23 => if llvm_major_ver >= 12 { "gfx1032" } else { "gfx1030" },
@@ -421,6 +656,10 @@ impl ASIC_NAME {
             Self::CHIP_GFX1101 => "gfx1101",
             Self::CHIP_GFX1102 => "gfx1102",
             Self::CHIP_GFX1103_R1 | Self::CHIP_GFX1103_R2 => "gfx1103",
+            Self::CHIP_GFX1150 => "gfx1150",
+            Self::CHIP_GFX1151 => "gfx1151",
+            Self::CHIP_GFX1152 => if llvm_major_ver >= 19 { "gfx1152" } else { "gfx1150" },
+            Self::CHIP_GFX1153 => if llvm_major_ver >= 20 { "gfx1153" } else { "gfx1150" },
             _ => "",
         }
     }
@@ -460,6 +699,7 @@ impl ASIC_NAME {
             Self::CHIP_NAVI10 => "gfx1010",
             Self::CHIP_NAVI12 => "gfx1011",
             Self::CHIP_NAVI14 => "gfx1012",
+            Self::CHIP_GFX1013 => "gfx1013",
             Self::CHIP_NAVI21 => "gfx1030",
             Self::CHIP_NAVI22 => "gfx1031",
             Self::CHIP_NAVI23 => "gfx1032",
@@ -472,9 +712,269 @@ impl ASIC_NAME {
             Self::CHIP_GFX1102 => "gfx1102",
             Self::CHIP_GFX1103_R1 |
             Self::CHIP_GFX1103_R2 => "gfx1103",
+            Self::CHIP_GFX1150 => "gfx1150",
+            Self::CHIP_GFX1151 => "gfx1151",
+            Self::CHIP_GFX1152 => "gfx1152",
+            Self::CHIP_GFX1153 => "gfx1153",
             _ => "",
         }
     }
+
+    /// Check if the code objects are compatible, i.e. the same [ASIC_NAME::get_gfx_target_name].
+    /// Returns `false` if the gfx target is unknown.
+    pub fn is_binary_compatible_with(&self, other: &ASIC_NAME) -> bool {
+        let target = self.get_gfx_target_name();
+
+        !target.is_empty() && target == other.get_gfx_target_name()
+    }
+
+    /// Approximate year of the first product with the ASIC, best-effort.  
+    /// Returns `None` for server/compute-only parts, non-retail parts and unknown ASICs.
+    pub fn approx_release_year(&self) -> Option<u16> {
+        let year = match self {
+            Self::CHIP_R300 => 2002,
+            Self::CHIP_R350 |
+            Self::CHIP_RV350 => 2003,
+            Self::CHIP_RV370 |
+            Self::CHIP_RV380 |
+            Self::CHIP_RS400 |
+            Self::CHIP_RS480 |
+            Self::CHIP_R420 |
+            Self::CHIP_R423 |
+            Self::CHIP_RV410 => 2004,
+            Self::CHIP_RC410 |
+            Self::CHIP_R430 |
+            Self::CHIP_R480 |
+            Self::CHIP_R481 |
+            Self::CHIP_RV515 |
+            Self::CHIP_R520 |
+            Self::CHIP_RV530 => 2005,
+            Self::CHIP_RS600 |
+            Self::CHIP_R580 |
+            Self::CHIP_RV560 |
+            Self::CHIP_RV570 => 2006,
+            Self::CHIP_RS690 |
+            Self::CHIP_RS740 |
+            Self::CHIP_R600 |
+            Self::CHIP_RV610 |
+            Self::CHIP_RV630 |
+            Self::CHIP_RV670 => 2007,
+            Self::CHIP_RV620 |
+            Self::CHIP_RV635 |
+            Self::CHIP_RS780 |
+            Self::CHIP_RV770 |
+            Self::CHIP_RV730 |
+            Self::CHIP_RV710 => 2008,
+            Self::CHIP_RS880 |
+            Self::CHIP_RV740 |
+            Self::CHIP_JUNIPER |
+            Self::CHIP_CYPRESS |
+            Self::CHIP_HEMLOCK => 2009,
+            Self::CHIP_CEDAR |
+            Self::CHIP_REDWOOD |
+            Self::CHIP_BARTS |
+            Self::CHIP_CAYMAN => 2010,
+            Self::CHIP_PALM |
+            Self::CHIP_SUMO |
+            Self::CHIP_SUMO2 |
+            Self::CHIP_TURKS |
+            Self::CHIP_CAICOS => 2011,
+            Self::CHIP_ARUBA |
+            Self::CHIP_TAHITI |
+            Self::CHIP_PITCAIRN |
+            Self::CHIP_VERDE => 2012,
+            Self::CHIP_OLAND |
+            Self::CHIP_HAINAN |
+            Self::CHIP_BONAIRE |
+            Self::CHIP_LIVERPOOL |
+            Self::CHIP_KABINI |
+            Self::CHIP_HAWAII => 2013,
+            Self::CHIP_KAVERI |
+            Self::CHIP_TONGA |
+            Self::CHIP_ICELAND => 2014,
+            Self::CHIP_CARRIZO |
+            Self::CHIP_FIJI => 2015,
+            Self::CHIP_GLADIUS |
+            Self::CHIP_STONEY |
+            Self::CHIP_POLARIS10 |
+            Self::CHIP_POLARIS11 => 2016,
+            Self::CHIP_POLARIS12 |
+            Self::CHIP_VEGA10 |
+            Self::CHIP_RAVEN => 2017,
+            Self::CHIP_VEGAM |
+            Self::CHIP_VEGA12 |
+            Self::CHIP_VEGA20 |
+            Self::CHIP_RAVEN2 => 2018,
+            Self::CHIP_NAVI10 |
+            Self::CHIP_NAVI14 => 2019,
+            Self::CHIP_RENOIR |
+            Self::CHIP_NAVI12 |
+            Self::CHIP_NAVI21 => 2020,
+            Self::CHIP_NAVI22 |
+            Self::CHIP_NAVI23 => 2021,
+            Self::CHIP_VANGOGH |
+            Self::CHIP_NAVI24 |
+            Self::CHIP_REMBRANDT |
+            Self::CHIP_GFX1036 |
+            Self::CHIP_GFX1100 => 2022,
+            Self::CHIP_GFX1101 |
+            Self::CHIP_GFX1102 |
+            Self::CHIP_GFX1103_R1 |
+            Self::CHIP_GFX1103_R2 => 2023,
+            Self::CHIP_GFX1150 => 2024,
+            Self::CHIP_GFX1151 |
+            Self::CHIP_GFX1152 => 2025,
+            /* not sold as a Radeon product */
+            Self::CHIP_GFX1013 |
+            Self::CHIP_ARCTURUS |
+            Self::CHIP_ALDEBARAN |
+            Self::CHIP_GFX940 |
+            Self::CHIP_GFX1153 |
+            Self::CHIP_UNKNOWN => return None,
+        };
+
+        Some(year)
+    }
+
+    /// Marketing series of the products with the ASIC (e.g. "Radeon RX 6000"), best-effort.  
+    /// Returns an empty string for server/compute-only parts, non-retail parts and unknown ASICs.
+    pub fn marketing_series(&self) -> &'static str {
+        match self {
+            Self::CHIP_R300 |
+            Self::CHIP_R350 |
+            Self::CHIP_RV350 => "Radeon 9000",
+            Self::CHIP_RV370 |
+            Self::CHIP_RV380 |
+            Self::CHIP_R420 |
+            Self::CHIP_R423 |
+            Self::CHIP_R430 |
+            Self::CHIP_R480 |
+            Self::CHIP_R481 |
+            Self::CHIP_RV410 => "Radeon X300-X850",
+            Self::CHIP_RS400 |
+            Self::CHIP_RC410 |
+            Self::CHIP_RS480 => "Radeon Xpress 200",
+            Self::CHIP_RS600 |
+            Self::CHIP_RS690 |
+            Self::CHIP_RS740 => "Radeon X1200 (IGP)",
+            Self::CHIP_RV515 |
+            Self::CHIP_R520 |
+            Self::CHIP_RV530 |
+            Self::CHIP_R580 |
+            Self::CHIP_RV560 |
+            Self::CHIP_RV570 => "Radeon X1000",
+            Self::CHIP_R600 |
+            Self::CHIP_RV610 |
+            Self::CHIP_RV630 => "Radeon HD 2000",
+            Self::CHIP_RV670 |
+            Self::CHIP_RV620 |
+            Self::CHIP_RV635 => "Radeon HD 3000",
+            Self::CHIP_RS780 => "Radeon HD 3000 (IGP)",
+            Self::CHIP_RS880 => "Radeon HD 4000 (IGP)",
+            Self::CHIP_RV770 |
+            Self::CHIP_RV730 |
+            Self::CHIP_RV710 |
+            Self::CHIP_RV740 => "Radeon HD 4000",
+            Self::CHIP_CEDAR |
+            Self::CHIP_REDWOOD |
+            Self::CHIP_JUNIPER |
+            Self::CHIP_CYPRESS |
+            Self::CHIP_HEMLOCK => "Radeon HD 5000",
+            Self::CHIP_PALM |
+            Self::CHIP_SUMO |
+            Self::CHIP_SUMO2 => "Radeon HD 6000 (APU)",
+            Self::CHIP_BARTS |
+            Self::CHIP_TURKS |
+            Self::CHIP_CAICOS |
+            Self::CHIP_CAYMAN => "Radeon HD 6000",
+            Self::CHIP_ARUBA => "Radeon HD 7000 (APU)",
+            Self::CHIP_TAHITI |
+            Self::CHIP_PITCAIRN |
+            Self::CHIP_VERDE => "Radeon HD 7000",
+            Self::CHIP_OLAND |
+            Self::CHIP_HAINAN => "Radeon HD 8000 / R5 / R7",
+            Self::CHIP_BONAIRE |
+            Self::CHIP_HAWAII => "Radeon R7 / R9 200",
+            Self::CHIP_KAVERI |
+            Self::CHIP_KABINI |
+            Self::CHIP_CARRIZO |
+            Self::CHIP_STONEY => "A-Series APU",
+            Self::CHIP_LIVERPOOL => "PlayStation 4",
+            Self::CHIP_GLADIUS => "PlayStation 4 Pro",
+            Self::CHIP_TONGA |
+            Self::CHIP_ICELAND => "Radeon R9 / R7 300",
+            Self::CHIP_FIJI => "Radeon R9 Fury",
+            Self::CHIP_POLARIS10 |
+            Self::CHIP_POLARIS11 |
+            Self::CHIP_POLARIS12 => "Radeon RX 400 / 500",
+            Self::CHIP_VEGAM => "Radeon RX Vega M",
+            Self::CHIP_VEGA10 => "Radeon RX Vega",
+            Self::CHIP_VEGA12 => "Radeon Pro Vega",
+            Self::CHIP_VEGA20 => "Radeon VII",
+            Self::CHIP_RAVEN => "Ryzen 2000 / 3000 APU",
+            Self::CHIP_RAVEN2 => "Athlon / Ryzen 3000 APU",
+            Self::CHIP_RENOIR => "Ryzen 4000 / 5000 APU",
+            Self::CHIP_NAVI10 |
+            Self::CHIP_NAVI14 => "Radeon RX 5000",
+            Self::CHIP_NAVI12 => "Radeon Pro 5000M",
+            Self::CHIP_NAVI21 |
+            Self::CHIP_NAVI22 |
+            Self::CHIP_NAVI23 |
+            Self::CHIP_NAVI24 => "Radeon RX 6000",
+            Self::CHIP_VANGOGH => "Steam Deck",
+            Self::CHIP_REMBRANDT => "Ryzen 6000 APU",
+            Self::CHIP_GFX1036 => "Ryzen 7000 (iGPU)",
+            Self::CHIP_GFX1100 |
+            Self::CHIP_GFX1101 |
+            Self::CHIP_GFX1102 => "Radeon RX 7000",
+            Self::CHIP_GFX1103_R1 |
+            Self::CHIP_GFX1103_R2 => "Ryzen 7040 / 8040 APU",
+            Self::CHIP_GFX1150 |
+            Self::CHIP_GFX1152 => "Ryzen AI 300",
+            Self::CHIP_GFX1151 => "Ryzen AI Max",
+            Self::CHIP_GFX1013 |
+            Self::CHIP_ARCTURUS |
+            Self::CHIP_ALDEBARAN |
+            Self::CHIP_GFX940 |
+            Self::CHIP_GFX1153 |
+            Self::CHIP_UNKNOWN => "",
+        }
+    }
+}
+
+#[test]
+fn test_asic_wave_size() {
+    assert_eq!(ASIC_NAME::CHIP_VEGA10.native_wave_size(), 64);
+    assert!(!ASIC_NAME::CHIP_VEGA10.supports_wave32());
+    assert_eq!(ASIC_NAME::CHIP_GFX940.native_wave_size(), 64);
+    assert_eq!(ASIC_NAME::CHIP_NAVI10.native_wave_size(), 32);
+    assert!(ASIC_NAME::CHIP_NAVI10.supports_wave32());
+}
+
+#[test]
+fn test_asic_release_metadata() {
+    assert_eq!(ASIC_NAME::CHIP_NAVI21.approx_release_year(), Some(2020));
+    assert_eq!(ASIC_NAME::CHIP_NAVI21.marketing_series(), "Radeon RX 6000");
+    assert_eq!(ASIC_NAME::CHIP_ALDEBARAN.approx_release_year(), None);
+    assert_eq!(ASIC_NAME::CHIP_ALDEBARAN.marketing_series(), "");
+
+    for asic in ASIC_NAME::all() {
+        assert_eq!(asic.approx_release_year().is_none(), asic.marketing_series().is_empty(), "{asic:?}");
+    }
+}
+
+#[test]
+fn test_asic_binary_compatible() {
+    let polaris = [ASIC_NAME::CHIP_POLARIS10, ASIC_NAME::CHIP_POLARIS11, ASIC_NAME::CHIP_POLARIS12];
+
+    for a in &polaris {
+        for b in &polaris {
+            assert!(a.is_binary_compatible_with(b));
+        }
+    }
+
+    assert!(!ASIC_NAME::CHIP_NAVI21.is_binary_compatible_with(&ASIC_NAME::CHIP_NAVI22));
+    assert!(!ASIC_NAME::CHIP_UNKNOWN.is_binary_compatible_with(&ASIC_NAME::CHIP_UNKNOWN));
 }
 
 #[test]
@@ -485,9 +985,146 @@ fn test_asic_name_get() {
     );
 }
 
+#[test]
+fn test_asic_name_rev_table_no_overlap() {
+    let families = [
+        FAMILY_NAME::SI,
+        FAMILY_NAME::CI,
+        FAMILY_NAME::KV,
+        FAMILY_NAME::VI,
+        FAMILY_NAME::CZ,
+        FAMILY_NAME::AI,
+        FAMILY_NAME::RV,
+        FAMILY_NAME::NV,
+        FAMILY_NAME::VGH,
+        FAMILY_NAME::GC_11_0_0,
+        FAMILY_NAME::YC,
+        FAMILY_NAME::GC_11_0_1,
+        FAMILY_NAME::GC_10_3_6,
+        FAMILY_NAME::GC_10_3_7,
+        FAMILY_NAME::GC_11_5_0,
+    ];
+
+    for family in families {
+        let table = ASIC_NAME::rev_table(family);
+
+        for (min, max, asic) in table {
+            assert!(min <= max, "{family:?}: {asic:?}");
+        }
+
+        for w in table.windows(2) {
+            assert!(w[0].1 < w[1].0, "{family:?}: {:?} and {:?} overlap", w[0].2, w[1].2);
+        }
+
+        for rev in 0..=0xFF {
+            let n = table.iter().filter(|(min, max, _)| (*min..=*max).contains(&rev)).count();
+            assert!(n <= 1, "{family:?}: rev {rev:#X} is ambiguous");
+        }
+    }
+
+    assert_eq!(ASIC_NAME::get(FAMILY_NAME::KV, 0x41), ASIC_NAME::CHIP_KAVERI);
+    assert_eq!(ASIC_NAME::get(FAMILY_NAME::KV, 0x42), ASIC_NAME::CHIP_LIVERPOOL);
+}
+
+#[test]
+fn test_asic_name_all() {
+    /* the last variant of ASIC_NAME */
+    assert_eq!(ASIC_NAME::ALL.len(), ASIC_NAME::CHIP_GFX1153 as usize);
+
+    for (i, asic) in ASIC_NAME::all().iter().enumerate() {
+        assert_eq!(*asic as usize, i + 1);
+    }
+}
+
 #[cfg(feature = "std")]
-use std::fmt;
-#[cfg(feature = "std")]
+#[test]
+fn test_asic_is_apu() {
+    let apu: Vec<ASIC_NAME> = ASIC_NAME::ALL.iter().copied().filter(ASIC_NAME::is_apu).collect();
+
+    assert_eq!(apu, [
+        ASIC_NAME::CHIP_PALM,
+        ASIC_NAME::CHIP_SUMO,
+        ASIC_NAME::CHIP_SUMO2,
+        ASIC_NAME::CHIP_ARUBA,
+        ASIC_NAME::CHIP_KAVERI,
+        ASIC_NAME::CHIP_LIVERPOOL,
+        ASIC_NAME::CHIP_GLADIUS,
+        ASIC_NAME::CHIP_KABINI,
+        ASIC_NAME::CHIP_CARRIZO,
+        ASIC_NAME::CHIP_STONEY,
+        ASIC_NAME::CHIP_RAVEN,
+        ASIC_NAME::CHIP_RAVEN2,
+        ASIC_NAME::CHIP_RENOIR,
+        ASIC_NAME::CHIP_GFX1013,
+        ASIC_NAME::CHIP_VANGOGH,
+        ASIC_NAME::CHIP_REMBRANDT,
+        ASIC_NAME::CHIP_GFX1036,
+        ASIC_NAME::CHIP_GFX1103_R1,
+        ASIC_NAME::CHIP_GFX1103_R2,
+        ASIC_NAME::CHIP_GFX1150,
+        ASIC_NAME::CHIP_GFX1151,
+        ASIC_NAME::CHIP_GFX1152,
+        ASIC_NAME::CHIP_GFX1153,
+    ]);
+    assert!(!ASIC_NAME::CHIP_UNKNOWN.is_apu());
+}
+
+#[test]
+fn test_gfx1013_capability() {
+    let asic = ASIC_NAME::get(FAMILY_NAME::NV, 0x84);
+
+    assert_eq!(asic, ASIC_NAME::CHIP_GFX1013);
+    assert_eq!(asic.chip_class(), CHIP_CLASS::GFX10);
+    assert!(!asic.rbplus_allowed());
+    assert!(asic.has_packed_math_16bit());
+    assert!(!asic.has_accelerated_dot_product());
+    assert_eq!(asic.max_wave64_per_simd(), 20);
+    assert_eq!(asic.num_simd_per_cu(), 2);
+    assert_eq!(asic.cu_group(), 2);
+    assert_eq!(asic.l1_cache_size(), 16 * 1024);
+    assert_eq!(asic.gl1_cache_size(), 128 * 1024);
+    assert_eq!(asic.l2_cache_line_size(), 128);
+    assert_eq!(asic.l3_cache_size_mb_per_channel(), 0);
+    assert_eq!(asic.get_gfx_target_name(), "gfx1013");
+}
+
+#[test]
+fn test_gfx115x_capability() {
+    for (rev, asic, target) in [
+        (0x01, ASIC_NAME::CHIP_GFX1150, "gfx1150"),
+        (0xC0, ASIC_NAME::CHIP_GFX1151, "gfx1151"),
+        (0x40, ASIC_NAME::CHIP_GFX1152, "gfx1152"),
+        (0x50, ASIC_NAME::CHIP_GFX1153, "gfx1153"),
+    ] {
+        assert_eq!(ASIC_NAME::get(FAMILY_NAME::GC_11_5_0, rev), asic);
+        assert_eq!(asic.chip_class(), CHIP_CLASS::GFX11_5);
+        assert_eq!(asic.get_gfx_target_name(), target);
+        assert!(asic.rbplus_allowed());
+        assert!(asic.has_accelerated_dot_product());
+        assert_eq!(asic.max_wave64_per_simd(), 16);
+        assert_eq!(asic.l1_cache_size(), 32 * 1024);
+        assert_eq!(asic.gl1_cache_size(), 256 * 1024);
+        assert_eq!(asic.l2_cache_line_size(), 128);
+        assert_eq!(asic.l3_cache_size_mb_per_channel(), 0);
+    }
+}
+
+/// Only uses `core`, to catch regressions of `no_std` support with `cargo test --no-default-features`
+#[cfg(not(feature = "std"))]
+#[test]
+fn test_asic_no_std() {
+    let asic = ASIC_NAME::get(FAMILY_NAME::NV, 0x28);
+
+    assert_eq!(asic, ASIC_NAME::CHIP_NAVI21);
+    assert_eq!(asic.chip_class(), CHIP_CLASS::GFX10_3);
+    assert_eq!(CHIP_CLASS::from_family(FAMILY_NAME::NV), CHIP_CLASS::GFX10);
+    assert_eq!(asic.get_gfx_target_name(), "gfx1030");
+    assert_eq!(asic.get_llvm_processor_name(17), "gfx1030");
+    assert_eq!(asic.l2_cache_line_size(), 128);
+    assert_eq!(asic.capabilities().num_simd_per_cu, 2);
+}
+
+use core::fmt;
 impl fmt::Display for ASIC_NAME {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -584,6 +1221,7 @@ impl fmt::Display for ASIC_NAME {
             Self::CHIP_NAVI10 => write!(f, "Navi10"),
             Self::CHIP_NAVI12 => write!(f, "Navi12"),
             Self::CHIP_NAVI14 => write!(f, "Navi14"),
+            Self::CHIP_GFX1013 => write!(f, "GFX1013/Cyan Skillfish"),
             /* GFX10.3 (RDNA 2) */
             Self::CHIP_NAVI21 => write!(f, "Sienna Cichlid/Navi21"),
             Self::CHIP_NAVI22 => write!(f, "Navy Flounder/Navi22"),
@@ -599,6 +1237,17 @@ impl fmt::Display for ASIC_NAME {
             Self::CHIP_GFX1103_R2 => write!(f, "GFX1103_R2"),
             Self::CHIP_GFX1150 => write!(f, "GFX1150"),
             Self::CHIP_GFX1151 => write!(f, "GFX1151"),
+            Self::CHIP_GFX1152 => write!(f, "GFX1152"),
+            Self::CHIP_GFX1153 => write!(f, "GFX1153"),
         }
     }
 }
+
+#[test]
+fn test_asic_topology() {
+    assert_eq!(ASIC_NAME::CHIP_NAVI21.num_shader_engines(), Some(4));
+    assert_eq!(ASIC_NAME::CHIP_NAVI21.num_shader_arrays_per_se(), Some(2));
+    assert_eq!(ASIC_NAME::CHIP_GFX1100.num_shader_engines(), Some(6));
+    assert_eq!(ASIC_NAME::CHIP_POLARIS10.num_shader_arrays_per_se(), Some(1));
+    assert_eq!(ASIC_NAME::CHIP_R600.num_shader_engines(), None);
+}
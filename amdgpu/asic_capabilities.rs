@@ -0,0 +1,36 @@
+use crate::AMDGPU::ASIC_NAME;
+
+/// Summary of the capabilities derived from [ASIC_NAME]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AsicCapabilities {
+    pub rbplus_allowed: bool,
+    pub has_packed_math_16bit: bool,
+    pub has_accelerated_dot_product: bool,
+    pub max_wave64_per_simd: u8,
+    pub num_simd_per_cu: u8,
+    /// Byte
+    pub l1_cache_size: u32,
+    /// Byte
+    pub gl1_cache_size: u32,
+    /// Byte
+    pub l2_cache_line_size: u32,
+    /// MiB
+    pub l3_cache_size_mb_per_channel: u32,
+}
+
+impl ASIC_NAME {
+    pub fn capabilities(&self) -> AsicCapabilities {
+        AsicCapabilities {
+            rbplus_allowed: self.rbplus_allowed(),
+            has_packed_math_16bit: self.has_packed_math_16bit(),
+            has_accelerated_dot_product: self.has_accelerated_dot_product(),
+            max_wave64_per_simd: self.max_wave64_per_simd(),
+            num_simd_per_cu: self.num_simd_per_cu(),
+            l1_cache_size: self.l1_cache_size(),
+            gl1_cache_size: self.gl1_cache_size(),
+            l2_cache_line_size: self.l2_cache_line_size(),
+            l3_cache_size_mb_per_channel: self.l3_cache_size_mb_per_channel(),
+        }
+    }
+}
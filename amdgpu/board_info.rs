@@ -0,0 +1,68 @@
+use crate::AMDGPU::DeviceHandle;
+use std::path::PathBuf;
+
+/// Board information from the FRU EEPROM, exposed in sysfs by datacenter/workstation cards
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BoardInfo {
+    /// `product_name`
+    pub product_name: Option<String>,
+    /// `product_number`
+    pub product_number: Option<String>,
+    /// `serial_number`
+    pub serial_number: Option<String>,
+    /// `fru_id`
+    pub fru_id: Option<String>,
+    /// `manufacturer`
+    pub manufacturer: Option<String>,
+}
+
+impl BoardInfo {
+    /// Missing or empty files are `None`, returns `None` if all of them are.
+    pub fn get_from_sysfs<P: Into<PathBuf>>(sysfs_path: P) -> Option<Self> {
+        let path = sysfs_path.into();
+        let files = [
+            "product_name",
+            "product_number",
+            "serial_number",
+            "fru_id",
+            "manufacturer",
+        ].map(|name| std::fs::read_to_string(path.join(name)).ok());
+
+        Self::parse(files.each_ref().map(|s| s.as_deref()))
+    }
+
+    /// Parse the contents of `product_name`, `product_number`, `serial_number`, `fru_id`
+    /// and `manufacturer` (in this order), see [BoardInfo::get_from_sysfs]
+    pub fn parse(files: [Option<&str>; 5]) -> Option<Self> {
+        let [product_name, product_number, serial_number, fru_id, manufacturer] = files.map(|s| {
+            let s = s?.trim();
+
+            (!s.is_empty()).then(|| s.to_string())
+        });
+
+        let info = Self { product_name, product_number, serial_number, fru_id, manufacturer };
+
+        (info != Self::default()).then_some(info)
+    }
+}
+
+impl DeviceHandle {
+    /// Read [BoardInfo] from sysfs, `None` on consumer cards without the FRU EEPROM
+    pub fn get_board_info(&self) -> Option<BoardInfo> {
+        let sysfs_path = self.get_sysfs_path().ok()?;
+
+        BoardInfo::get_from_sysfs(sysfs_path)
+    }
+}
+
+#[test]
+fn test_board_info_parse() {
+    assert_eq!(BoardInfo::parse([None; 5]), None);
+
+    let info = BoardInfo::parse([Some("AMD Instinct MI210\n"), None, Some("\n"), None, None]);
+
+    assert_eq!(info, Some(BoardInfo {
+        product_name: Some("AMD Instinct MI210".to_string()),
+        ..Default::default()
+    }));
+}
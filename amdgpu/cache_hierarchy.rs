@@ -0,0 +1,92 @@
+use crate::AMDGPU::ASIC_NAME;
+use core::fmt;
+
+/// Cache sizes derived from [ASIC_NAME], per unit.
+/// Use the `total_*` methods with the unit counts of the device
+/// (e.g. `cu_active_number`, `num_tcc_blocks` of `drm_amdgpu_info_device`) for the totals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CacheHierarchy {
+    /// per CU, Byte
+    pub l1_cache_size: u32,
+    /// per ShaderArray, Byte (`0` before RDNA)
+    pub gl1_cache_size: u32,
+    /// per block (channel), Byte
+    pub l2_cache_size_per_block: u32,
+    /// Byte
+    pub l2_cache_line_size: u32,
+    /// per memory channel, MiB (`0` if no Infinity Cache)
+    pub l3_cache_size_mb_per_channel: u32,
+}
+
+impl CacheHierarchy {
+    /// Byte
+    pub fn total_l1(&self, num_cu: u32) -> u64 {
+        self.l1_cache_size as u64 * num_cu as u64
+    }
+
+    /// Byte
+    pub fn total_gl1(&self, num_sa: u32) -> u64 {
+        self.gl1_cache_size as u64 * num_sa as u64
+    }
+
+    /// Byte
+    pub fn total_l2(&self, num_channels: u32) -> u64 {
+        self.l2_cache_size_per_block as u64 * num_channels as u64
+    }
+
+    /// MiB
+    pub fn total_l3_mb(&self, num_channels: u32) -> u32 {
+        self.l3_cache_size_mb_per_channel.saturating_mul(num_channels)
+    }
+}
+
+impl ASIC_NAME {
+    pub fn cache_hierarchy(&self) -> CacheHierarchy {
+        CacheHierarchy {
+            l1_cache_size: self.l1_cache_size(),
+            gl1_cache_size: self.gl1_cache_size(),
+            l2_cache_size_per_block: self.l2_cache_size_per_block(),
+            l2_cache_line_size: self.l2_cache_line_size(),
+            l3_cache_size_mb_per_channel: self.l3_cache_size_mb_per_channel(),
+        }
+    }
+}
+
+/*
+    L1 :   32 KiB / CU
+    GL1:  256 KiB / SA
+    L2 :  256 KiB / channel (128 B line)
+    L3 :    4 MiB / channel
+*/
+impl fmt::Display for CacheHierarchy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        const KIB: u32 = 1 << 10;
+
+        writeln!(f, "L1 : {:>4} KiB / CU", self.l1_cache_size / KIB)?;
+        writeln!(f, "GL1: {:>4} KiB / SA", self.gl1_cache_size / KIB)?;
+        writeln!(
+            f,
+            "L2 : {:>4} KiB / channel ({} B line)",
+            self.l2_cache_size_per_block / KIB,
+            self.l2_cache_line_size,
+        )?;
+        write!(f, "L3 : {:>4} MiB / channel", self.l3_cache_size_mb_per_channel)
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_cache_hierarchy() {
+    let cache = ASIC_NAME::CHIP_NAVI21.cache_hierarchy();
+
+    assert_eq!(cache.total_l2(16), 4 << 20);
+    assert_eq!(cache.total_l3_mb(16), 128);
+    assert_eq!(
+        cache.to_string(),
+        "L1 :   16 KiB / CU\n\
+         GL1:  128 KiB / SA\n\
+         L2 :  256 KiB / channel (128 B line)\n\
+         L3 :    8 MiB / channel",
+    );
+}
@@ -0,0 +1,88 @@
+use crate::AMDGPU::{AsicCapabilities, ASIC_NAME, CHIP_CLASS, DeviceHandle, DeviceIdsFlags, GPU_INFO};
+use core::fmt;
+
+/// What the device can do, in one place
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CapabilitySummary {
+    pub asic_name: ASIC_NAME,
+    pub chip_class: CHIP_CLASS,
+    pub is_apu: bool,
+    pub resizable_bar: bool,
+    /// Trusted Memory Zone
+    pub tmz: bool,
+    pub asic: AsicCapabilities,
+    /// Byte
+    pub l2_cache_size: u32,
+    /// MiB
+    pub l3_cache_size_mb: u32,
+}
+
+impl DeviceHandle {
+    pub fn capability_summary(&self) -> Result<CapabilitySummary, i32> {
+        let info = self.device_info()?;
+        let memory_info = self.memory_info()?;
+        let asic_name = info.get_asic_name();
+        let flags = DeviceIdsFlags::new(info.ids_flags);
+
+        Ok(CapabilitySummary {
+            asic_name,
+            chip_class: info.get_chip_class(),
+            is_apu: info.is_apu(),
+            resizable_bar: memory_info.check_resizable_bar(),
+            tmz: flags.supports_tmz(),
+            asic: asic_name.capabilities(),
+            l2_cache_size: info.calc_l2_cache_size(),
+            l3_cache_size_mb: info.calc_l3_cache_size_mb(),
+        })
+    }
+}
+
+/*
+    ASIC Name        : Sienna Cichlid/Navi21
+    Chip Class       : GFX10.3 (RDNA 2)
+    APU              : false
+    ...
+*/
+impl fmt::Display for CapabilitySummary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        const KIB: u32 = 1 << 10;
+
+        writeln!(f, "ASIC Name        : {}", self.asic_name)?;
+        writeln!(f, "Chip Class       : {}", self.chip_class)?;
+        writeln!(f, "APU              : {}", self.is_apu)?;
+        writeln!(f, "Resizable BAR    : {}", self.resizable_bar)?;
+        writeln!(f, "TMZ              : {}", self.tmz)?;
+        writeln!(f, "RB+              : {}", self.asic.rbplus_allowed)?;
+        writeln!(f, "Packed Math 16bit: {}", self.asic.has_packed_math_16bit)?;
+        writeln!(f, "Dot Product      : {}", self.asic.has_accelerated_dot_product)?;
+        writeln!(f, "L1 Cache per CU  : {:>5} KiB", self.asic.l1_cache_size / KIB)?;
+        writeln!(f, "GL1 Cache per SA : {:>5} KiB", self.asic.gl1_cache_size / KIB)?;
+        writeln!(f, "L2 Cache         : {:>5} KiB", self.l2_cache_size / KIB)?;
+        write!(f, "L3 Cache         : {:>5} MiB", self.l3_cache_size_mb)
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_capability_summary_display() {
+    let asic_name = ASIC_NAME::CHIP_NAVI21;
+    let summary = CapabilitySummary {
+        asic_name,
+        chip_class: asic_name.chip_class(),
+        is_apu: false,
+        resizable_bar: true,
+        tmz: true,
+        asic: asic_name.capabilities(),
+        l2_cache_size: 4 << 20,
+        l3_cache_size_mb: 128,
+    };
+    let s = summary.to_string();
+
+    assert_eq!(s.lines().count(), 12);
+    assert!(s.starts_with("ASIC Name        : Sienna Cichlid/Navi21\n"));
+    assert!(s.contains("Chip Class       : GFX10.3 (RDNA 2)\n"));
+    assert!(s.contains("L1 Cache per CU  :    16 KiB\n"));
+    assert!(s.contains("GL1 Cache per SA :   128 KiB\n"));
+    assert!(s.contains("L2 Cache         :  4096 KiB\n"));
+    assert!(s.ends_with("L3 Cache         :   128 MiB"));
+}
@@ -28,7 +28,7 @@
     Commit: dda718d2bfe9309145d8e521c59c617e7674045a
 */
 
-use crate::AMDGPU::ASIC_NAME;
+use crate::AMDGPU::{ASIC_NAME, FAMILY_NAME, GfxTargetVersion};
 
 /// List of AMDGPU chip class (generation)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd)]
@@ -49,11 +49,15 @@ pub enum CHIP_CLASS {
     GFX10,
     GFX10_3,
     GFX11,
+    GFX11_5,
+    GFX12,
 }
 
 impl From<ASIC_NAME> for CHIP_CLASS {
     fn from(asic_name: ASIC_NAME) -> Self {
-        if asic_name >= ASIC_NAME::CHIP_GFX1100 {
+        if asic_name >= ASIC_NAME::CHIP_GFX1150 {
+            Self::GFX11_5
+        } else if asic_name >= ASIC_NAME::CHIP_GFX1100 {
             Self::GFX11
         } else if asic_name >= ASIC_NAME::CHIP_NAVI21 {
             Self::GFX10_3
@@ -73,7 +77,95 @@ impl From<ASIC_NAME> for CHIP_CLASS {
     }
 }
 
+impl From<FAMILY_NAME> for CHIP_CLASS {
+    fn from(family: FAMILY_NAME) -> Self {
+        Self::from_family(family)
+    }
+}
+
+impl From<GfxTargetVersion> for CHIP_CLASS {
+    fn from(ver: GfxTargetVersion) -> Self {
+        Self::from_gfx_version(ver.major, ver.minor)
+    }
+}
+
 impl CHIP_CLASS {
+    /// Approximate [CHIP_CLASS] from [FAMILY_NAME] only, without `chip_external_rev`.  
+    /// Some families span multiple classes (e.g. [FAMILY_NAME::NV] has GFX10 and GFX10_3),
+    /// in which case the class of the first ASIC in the family is returned.
+    /// Use [ASIC_NAME::chip_class] for the exact class.
+    pub const fn from_family(family: FAMILY_NAME) -> Self {
+        match family {
+            FAMILY_NAME::SI => Self::GFX6,
+            FAMILY_NAME::CI |
+            FAMILY_NAME::KV => Self::GFX7,
+            FAMILY_NAME::VI |
+            FAMILY_NAME::CZ => Self::GFX8,
+            FAMILY_NAME::AI |
+            FAMILY_NAME::RV => Self::GFX9,
+            FAMILY_NAME::NV => Self::GFX10,
+            FAMILY_NAME::VGH |
+            FAMILY_NAME::YC |
+            FAMILY_NAME::GC_10_3_6 |
+            FAMILY_NAME::GC_10_3_7 => Self::GFX10_3,
+            FAMILY_NAME::GC_11_0_0 |
+            FAMILY_NAME::GC_11_0_1 => Self::GFX11,
+            FAMILY_NAME::GC_11_5_0 => Self::GFX11_5,
+            FAMILY_NAME::UNKNOWN => Self::CLASS_UNKNOWN,
+        }
+    }
+
+    /// [CHIP_CLASS] from the GFX IP version (e.g. `(10, 3)` for gfx1030, `(11, 5)` for gfx1150)
+    pub const fn from_gfx_version(major: u32, minor: u32) -> Self {
+        match (major, minor) {
+            (6, _) => Self::GFX6,
+            (7, _) => Self::GFX7,
+            (8, _) => Self::GFX8,
+            (9, _) => Self::GFX9,
+            (10, 0..=1) => Self::GFX10,
+            (10, 3) => Self::GFX10_3,
+            (11, 0) => Self::GFX11,
+            (11, 5) => Self::GFX11_5,
+            (12, _) => Self::GFX12,
+            _ => Self::CLASS_UNKNOWN,
+        }
+    }
+
+    /// Architecture generation, e.g. "RDNA 3".  
+    /// GFX9 is "GCN 5" (Vega), use [ASIC_NAME::marketing_generation] to tell the CDNA ASICs apart.
+    pub const fn marketing_generation(&self) -> &'static str {
+        match self {
+            Self::CLASS_UNKNOWN => "Unknown",
+            Self::R300 |
+            Self::R400 |
+            Self::R500 => "Pre-TeraScale",
+            Self::R600 |
+            Self::R700 => "TeraScale",
+            Self::EVERGREEN => "TeraScale 2",
+            Self::CAYMAN => "TeraScale 3",
+            Self::GFX6 => "GCN 1",
+            Self::GFX7 => "GCN 2",
+            Self::GFX8 => "GCN 3/4",
+            Self::GFX9 => "GCN 5",
+            Self::GFX10 => "RDNA 1",
+            Self::GFX10_3 => "RDNA 2",
+            Self::GFX11 => "RDNA 3",
+            Self::GFX11_5 => "RDNA 3.5",
+            Self::GFX12 => "RDNA 4",
+        }
+    }
+
+    /// Codename of the GCN generation (e.g. "Southern Islands"), `None` for the others
+    pub const fn island_name(&self) -> Option<&'static str> {
+        match self {
+            Self::GFX6 => Some("Southern Islands"),
+            Self::GFX7 => Some("Sea Islands"),
+            Self::GFX8 => Some("Volcanic Islands"),
+            Self::GFX9 => Some("Vega"),
+            _ => None,
+        }
+    }
+
     pub fn has_packed_math_16bit(&self) -> bool {
         *self >= Self::GFX9
     }
@@ -92,27 +184,68 @@ fn test_amdgpu_chip_class() {
     assert_eq!(ASIC_NAME::CHIP_POLARIS11.chip_class(), CHIP_CLASS::GFX8,)
 }
 
-#[cfg(feature = "std")]
-use std::fmt;
-#[cfg(feature = "std")]
+#[test]
+fn test_chip_class_from_family() {
+    assert_eq!(CHIP_CLASS::from_family(FAMILY_NAME::NV), CHIP_CLASS::GFX10);
+    assert_eq!(CHIP_CLASS::from(FAMILY_NAME::GC_11_0_0), CHIP_CLASS::GFX11);
+    assert_eq!(
+        CHIP_CLASS::from_family(FAMILY_NAME::GC_11_5_0),
+        ASIC_NAME::get(FAMILY_NAME::GC_11_5_0, 0x01).chip_class(),
+    );
+}
+
+#[test]
+fn test_chip_class_from_gfx_version() {
+    assert_eq!(CHIP_CLASS::from_gfx_version(10, 3), CHIP_CLASS::GFX10_3);
+    assert_eq!(CHIP_CLASS::from_gfx_version(10, 1), CHIP_CLASS::GFX10);
+    assert_eq!(CHIP_CLASS::from_gfx_version(11, 0), CHIP_CLASS::GFX11);
+    assert_eq!(CHIP_CLASS::from_gfx_version(11, 5), CHIP_CLASS::GFX11_5);
+    assert_eq!(CHIP_CLASS::from_gfx_version(12, 0), CHIP_CLASS::GFX12);
+    assert_eq!(CHIP_CLASS::from_gfx_version(10, 2), CHIP_CLASS::CLASS_UNKNOWN);
+    assert_eq!(
+        CHIP_CLASS::from(GfxTargetVersion { major: 9, minor: 0, stepping: 10 }),
+        CHIP_CLASS::GFX9,
+    );
+}
+
+use core::fmt;
+/// e.g. "GFX11 (RDNA 3)"
 impl fmt::Display for CHIP_CLASS {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            Self::CLASS_UNKNOWN => write!(f, "Unknown"),
-            Self::R300 => write!(f, "R300"),
-            Self::R400 => write!(f, "R400"),
-            Self::R500 => write!(f, "R500"),
-            Self::R600 => write!(f, "R600"),
-            Self::R700 => write!(f, "R700"),
-            Self::EVERGREEN => write!(f, "Evergreen"),
-            Self::CAYMAN => write!(f, "Cayman"),
-            Self::GFX6 => write!(f, "GFX6"),
-            Self::GFX7 => write!(f, "GFX7"),
-            Self::GFX8 => write!(f, "GFX8"),
-            Self::GFX9 => write!(f, "GFX9"),
-            Self::GFX10 => write!(f, "GFX10"),
-            Self::GFX10_3 => write!(f, "GFX10_3"),
-            Self::GFX11 => write!(f, "GFX11"),
-        }
+        let name = match self {
+            Self::CLASS_UNKNOWN => return write!(f, "Unknown"),
+            Self::R300 => "R300",
+            Self::R400 => "R400",
+            Self::R500 => "R500",
+            Self::R600 => "R600",
+            Self::R700 => "R700",
+            Self::EVERGREEN => "Evergreen",
+            Self::CAYMAN => "Cayman",
+            Self::GFX6 => "GFX6",
+            Self::GFX7 => "GFX7",
+            Self::GFX8 => "GFX8",
+            Self::GFX9 => "GFX9",
+            Self::GFX10 => "GFX10",
+            Self::GFX10_3 => "GFX10.3",
+            Self::GFX11 => "GFX11",
+            Self::GFX11_5 => "GFX11.5",
+            Self::GFX12 => "GFX12",
+        };
+
+        write!(f, "{name} ({})", self.marketing_generation())
     }
 }
+
+#[cfg(feature = "std")]
+#[test]
+fn test_chip_class_display() {
+    assert_eq!(CHIP_CLASS::GFX11.to_string(), "GFX11 (RDNA 3)");
+    assert_eq!(CHIP_CLASS::GFX10_3.to_string(), "GFX10.3 (RDNA 2)");
+    assert_eq!(CHIP_CLASS::GFX6.to_string(), "GFX6 (GCN 1)");
+    assert_eq!(CHIP_CLASS::GFX12.marketing_generation(), "RDNA 4");
+    assert_eq!(ASIC_NAME::CHIP_VEGA20.marketing_generation(), "GCN 5");
+    assert_eq!(ASIC_NAME::CHIP_ARCTURUS.marketing_generation(), "CDNA 1");
+    assert_eq!(ASIC_NAME::CHIP_ALDEBARAN.marketing_generation(), "CDNA 2");
+    assert_eq!(ASIC_NAME::CHIP_GFX940.marketing_generation(), "CDNA 3");
+    assert_eq!(CHIP_CLASS::GFX7.island_name(), Some("Sea Islands"));
+}
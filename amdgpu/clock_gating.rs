@@ -0,0 +1,146 @@
+use crate::AMDGPU::DeviceHandle;
+use std::path::PathBuf;
+
+/// Clock gating features currently enabled by the driver (`AMD_CG_SUPPORT_*` of `amd_shared.h`).
+/// The source is the first line of debugfs `amdgpu_pm_info` ("Clock Gating Flags Mask"),
+/// the kernel does not expose the flags via `AMDGPU_INFO_DEV_INFO` or sysfs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ClockGatingFlags(u64);
+
+impl ClockGatingFlags {
+    pub const GFX_MGCG: Self = Self(1 << 0);
+    pub const GFX_MGLS: Self = Self(1 << 1);
+    pub const GFX_CGCG: Self = Self(1 << 2);
+    pub const GFX_CGLS: Self = Self(1 << 3);
+    pub const GFX_CGTS: Self = Self(1 << 4);
+    pub const GFX_CGTS_LS: Self = Self(1 << 5);
+    pub const GFX_CP_LS: Self = Self(1 << 6);
+    pub const GFX_RLC_LS: Self = Self(1 << 7);
+    pub const MC_LS: Self = Self(1 << 8);
+    pub const MC_MGCG: Self = Self(1 << 9);
+    pub const SDMA_LS: Self = Self(1 << 10);
+    pub const SDMA_MGCG: Self = Self(1 << 11);
+    pub const BIF_LS: Self = Self(1 << 12);
+    pub const UVD_MGCG: Self = Self(1 << 13);
+    pub const VCE_MGCG: Self = Self(1 << 14);
+    pub const HDP_LS: Self = Self(1 << 15);
+    pub const HDP_MGCG: Self = Self(1 << 16);
+    pub const ROM_MGCG: Self = Self(1 << 17);
+    pub const DRM_LS: Self = Self(1 << 18);
+    pub const BIF_MGCG: Self = Self(1 << 19);
+    pub const GFX_3D_CGCG: Self = Self(1 << 20);
+    pub const GFX_3D_CGLS: Self = Self(1 << 21);
+    pub const DRM_MGCG: Self = Self(1 << 22);
+    pub const DF_MGCG: Self = Self(1 << 23);
+    pub const VCN_MGCG: Self = Self(1 << 24);
+    pub const HDP_DS: Self = Self(1 << 25);
+    pub const HDP_SD: Self = Self(1 << 26);
+    pub const IH_CG: Self = Self(1 << 27);
+    pub const ATHUB_LS: Self = Self(1 << 28);
+    pub const ATHUB_MGCG: Self = Self(1 << 29);
+    pub const JPEG_MGCG: Self = Self(1 << 30);
+    pub const GFX_FGCG: Self = Self(1 << 31);
+    pub const REPEATER_FGCG: Self = Self(1 << 32);
+    pub const GFX_PERF_CLK: Self = Self(1 << 33);
+
+    const NAMES: [(Self, &'static str); 34] = [
+        (Self::GFX_MGCG, "GFX_MGCG"),
+        (Self::GFX_MGLS, "GFX_MGLS"),
+        (Self::GFX_CGCG, "GFX_CGCG"),
+        (Self::GFX_CGLS, "GFX_CGLS"),
+        (Self::GFX_CGTS, "GFX_CGTS"),
+        (Self::GFX_CGTS_LS, "GFX_CGTS_LS"),
+        (Self::GFX_CP_LS, "GFX_CP_LS"),
+        (Self::GFX_RLC_LS, "GFX_RLC_LS"),
+        (Self::MC_LS, "MC_LS"),
+        (Self::MC_MGCG, "MC_MGCG"),
+        (Self::SDMA_LS, "SDMA_LS"),
+        (Self::SDMA_MGCG, "SDMA_MGCG"),
+        (Self::BIF_LS, "BIF_LS"),
+        (Self::UVD_MGCG, "UVD_MGCG"),
+        (Self::VCE_MGCG, "VCE_MGCG"),
+        (Self::HDP_LS, "HDP_LS"),
+        (Self::HDP_MGCG, "HDP_MGCG"),
+        (Self::ROM_MGCG, "ROM_MGCG"),
+        (Self::DRM_LS, "DRM_LS"),
+        (Self::BIF_MGCG, "BIF_MGCG"),
+        (Self::GFX_3D_CGCG, "GFX_3D_CGCG"),
+        (Self::GFX_3D_CGLS, "GFX_3D_CGLS"),
+        (Self::DRM_MGCG, "DRM_MGCG"),
+        (Self::DF_MGCG, "DF_MGCG"),
+        (Self::VCN_MGCG, "VCN_MGCG"),
+        (Self::HDP_DS, "HDP_DS"),
+        (Self::HDP_SD, "HDP_SD"),
+        (Self::IH_CG, "IH_CG"),
+        (Self::ATHUB_LS, "ATHUB_LS"),
+        (Self::ATHUB_MGCG, "ATHUB_MGCG"),
+        (Self::JPEG_MGCG, "JPEG_MGCG"),
+        (Self::GFX_FGCG, "GFX_FGCG"),
+        (Self::REPEATER_FGCG, "REPEATER_FGCG"),
+        (Self::GFX_PERF_CLK, "GFX_PERF_CLK"),
+    ];
+
+    pub const fn from_bits(bits: u64) -> Self {
+        Self(bits)
+    }
+
+    pub const fn bits(&self) -> u64 {
+        self.0
+    }
+
+    pub const fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Names of the enabled flags, unknown bits are skipped
+    pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        Self::NAMES.iter().filter(|(flag, _)| self.contains(*flag)).map(|(_, name)| *name)
+    }
+
+    /// Read debugfs `amdgpu_pm_info`, requires root privileges
+    pub fn get_from_debugfs<P: Into<PathBuf>>(debug_dri_path: P) -> Option<Self> {
+        let s = std::fs::read_to_string(debug_dri_path.into().join("amdgpu_pm_info")).ok()?;
+
+        Self::parse(&s)
+    }
+
+    /// Parse "Clock Gating Flags Mask: 0x..." in `amdgpu_pm_info`
+    pub fn parse(s: &str) -> Option<Self> {
+        let mask = s.lines().find_map(|line| line.trim().strip_prefix("Clock Gating Flags Mask:"))?;
+        let mask = u64::from_str_radix(mask.trim().trim_start_matches("0x"), 16).ok()?;
+
+        Some(Self(mask))
+    }
+}
+
+impl std::ops::BitOr for ClockGatingFlags {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl DeviceHandle {
+    /// Clock gating features currently enabled, from debugfs `amdgpu_pm_info`.
+    /// Returns `None` if debugfs is not mounted or not readable (requires root privileges).
+    pub fn get_clock_gating_flags(&self) -> Option<ClockGatingFlags> {
+        let debug_dri_path = self.get_pci_bus_info().ok()?.get_debug_dri_path().ok()?;
+
+        ClockGatingFlags::get_from_debugfs(debug_dri_path)
+    }
+}
+
+#[test]
+fn test_clock_gating_flags() {
+    let s = "Clock Gating Flags Mask: 0x10000000f\n\
+        \tGraphics Medium Grain Clock Gating: On\n";
+    let flags = ClockGatingFlags::parse(s).unwrap();
+
+    assert!(flags.contains(ClockGatingFlags::GFX_MGCG | ClockGatingFlags::GFX_CGLS));
+    assert!(!flags.contains(ClockGatingFlags::VCN_MGCG));
+    assert_eq!(
+        flags.names().collect::<Vec<_>>(),
+        ["GFX_MGCG", "GFX_MGLS", "GFX_CGCG", "GFX_CGLS", "REPEATER_FGCG"],
+    );
+}
@@ -1,6 +1,7 @@
 use crate::AMDGPU::DeviceHandle;
 use crate::query_error;
 use crate::bindings::{self, amdgpu_context_handle};
+use crate::ffi;
 use core::mem::MaybeUninit;
 
 pub struct ContextHandle(pub(crate) amdgpu_context_handle);
@@ -10,7 +11,7 @@ impl DeviceHandle {
         unsafe {
             let mut ctx_handle: MaybeUninit<amdgpu_context_handle> = MaybeUninit::zeroed();
 
-            let r = bindings::amdgpu_cs_ctx_create(self.0, ctx_handle.as_mut_ptr());
+            let r = ffi::amdgpu_cs_ctx_create(self.0, ctx_handle.as_mut_ptr());
 
             let ctx_handle = ContextHandle::new(ctx_handle.assume_init());
 
@@ -27,7 +28,7 @@ impl ContextHandle {
     }
 
     unsafe fn free(&self) -> Result<(), i32> {
-        let r = bindings::amdgpu_cs_ctx_free(self.0);
+        let r = ffi::amdgpu_cs_ctx_free(self.0);
 
         query_error!(r);
 
@@ -42,7 +43,7 @@ impl ContextHandle {
         unsafe {
             let mut out_flags: MaybeUninit<u32> = MaybeUninit::zeroed();
 
-            let r = bindings::amdgpu_cs_ctx_stable_pstate(
+            let r = ffi::amdgpu_cs_ctx_stable_pstate(
                 self.0,
                 op,
                 pstate_flag as u32,
@@ -0,0 +1,186 @@
+use crate::AMDGPU::{DeviceHandle, DpmLevel, GpuMetrics, MetricsInfo, SENSOR_INFO::SENSOR_TYPE};
+use std::path::PathBuf;
+
+/// Current clocks in MHz, each `None` if no source reports it.
+/// `vclk`, `dclk`, `soc` and `fclk` are only reported by newer ASICs
+/// (mostly via `gpu_metrics` or `pp_dpm_*` of SMU v11 and later).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CurrentClocks {
+    pub gfx: Option<u32>,
+    pub mem: Option<u32>,
+    pub vclk: Option<u32>,
+    pub dclk: Option<u32>,
+    pub soc: Option<u32>,
+    pub fclk: Option<u32>,
+}
+
+impl CurrentClocks {
+    /// `0xFFFF` (unsupported) and `0` are treated as `None`
+    pub fn from_gpu_metrics(metrics: &GpuMetrics) -> Self {
+        let valid = |v: Option<u16>| v.filter(|v| *v != 0 && *v != u16::MAX).map(u32::from);
+
+        Self {
+            gfx: valid(metrics.get_current_gfxclk()),
+            mem: valid(metrics.get_current_uclk()),
+            vclk: valid(metrics.get_current_vclk()),
+            dclk: valid(metrics.get_current_dclk()),
+            soc: valid(metrics.get_current_socclk()),
+            fclk: valid(metrics.get_current_fclk()),
+        }
+    }
+
+    /// Active level of `pp_dpm_{sclk,mclk,vclk,dclk,socclk,fclk}`
+    pub fn get_from_sysfs<P: Into<PathBuf>>(sysfs_path: P) -> Self {
+        let path = sysfs_path.into();
+        let pp_dpm = [
+            "pp_dpm_sclk",
+            "pp_dpm_mclk",
+            "pp_dpm_vclk",
+            "pp_dpm_dclk",
+            "pp_dpm_socclk",
+            "pp_dpm_fclk",
+        ].map(|name| std::fs::read_to_string(path.join(name)).ok());
+
+        Self::parse_pp_dpm(pp_dpm.each_ref().map(|s| s.as_deref()))
+    }
+
+    /// Active level of the contents of `pp_dpm_{sclk,mclk,vclk,dclk,socclk,fclk}` (in this order),
+    /// `None` for a missing file
+    pub fn parse_pp_dpm(pp_dpm: [Option<&str>; 6]) -> Self {
+        let [gfx, mem, vclk, dclk, soc, fclk] = pp_dpm.map(|s| DpmLevel::active_clock(s?));
+
+        Self { gfx, mem, vclk, dclk, soc, fclk }
+    }
+
+    /// Fill the `None` fields with `other`
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            gfx: self.gfx.or(other.gfx),
+            mem: self.mem.or(other.mem),
+            vclk: self.vclk.or(other.vclk),
+            dclk: self.dclk.or(other.dclk),
+            soc: self.soc.or(other.soc),
+            fclk: self.fclk.or(other.fclk),
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        [self.gfx, self.mem, self.vclk, self.dclk, self.soc, self.fclk].iter().all(Option::is_some)
+    }
+}
+
+/// Current clocks of the video engine (UVD/VCE/VCN) in MHz, each `None` if not reported.  
+/// `Some(0)` means the engine is idle (power gated).
+/// `vclk1`/`dclk1` are the second VCN instance, only on ASICs with two VCN instances.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct VideoClocks {
+    pub vclk: Option<u32>,
+    pub dclk: Option<u32>,
+    pub vclk1: Option<u32>,
+    pub dclk1: Option<u32>,
+}
+
+impl VideoClocks {
+    /// `0xFFFF` (unsupported) is treated as `None`
+    pub fn from_gpu_metrics(metrics: &GpuMetrics) -> Self {
+        let valid = |v: Option<u16>| v.filter(|v| *v != u16::MAX).map(u32::from);
+
+        Self {
+            vclk: valid(metrics.get_current_vclk()),
+            dclk: valid(metrics.get_current_dclk()),
+            vclk1: valid(metrics.get_current_vclk1()),
+            dclk1: valid(metrics.get_current_dclk1()),
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        [self.vclk, self.dclk, self.vclk1, self.dclk1].iter().any(|v| v.is_some_and(|v| v != 0))
+    }
+}
+
+impl DeviceHandle {
+    /// Current clocks from the richest source available, field by field:
+    ///  1. `gpu_metrics` (SMU v11 and later, APUs)
+    ///  2. `AMDGPU_INFO_SENSOR` (`gfx` and `mem` only)
+    ///  3. active level of `pp_dpm_*` in sysfs
+    pub fn get_current_clocks(&self) -> CurrentClocks {
+        let mut clocks = self.get_gpu_metrics()
+            .map(|metrics| CurrentClocks::from_gpu_metrics(&metrics))
+            .unwrap_or_default();
+
+        if clocks.gfx.is_none() {
+            clocks.gfx = self.query_sensor(SENSOR_TYPE::GFX_SCLK).ok();
+        }
+
+        if clocks.mem.is_none() {
+            clocks.mem = self.query_sensor(SENSOR_TYPE::GFX_MCLK).ok();
+        }
+
+        if clocks.is_complete() {
+            return clocks;
+        }
+
+        match self.get_sysfs_path() {
+            Ok(sysfs_path) => clocks.or(CurrentClocks::get_from_sysfs(sysfs_path)),
+            Err(_) => clocks,
+        }
+    }
+
+    /// Current video engine clocks from `gpu_metrics`,
+    /// or the active level of `pp_dpm_vclk`/`pp_dpm_dclk` if `gpu_metrics` is not supported.  
+    /// `AMDGPU_INFO_SENSOR` has no IDs for the video engine clocks.
+    /// The DPM level does not drop to 0 when the engine is idle, unlike `gpu_metrics`.
+    pub fn get_video_clocks(&self) -> VideoClocks {
+        if let Ok(metrics) = self.get_gpu_metrics() {
+            let clocks = VideoClocks::from_gpu_metrics(&metrics);
+
+            if clocks.vclk.is_some() || clocks.dclk.is_some() {
+                return clocks;
+            }
+        }
+
+        let Ok(sysfs_path) = self.get_sysfs_path() else { return VideoClocks::default() };
+        let CurrentClocks { vclk, dclk, .. } = CurrentClocks::get_from_sysfs(sysfs_path);
+
+        VideoClocks { vclk, dclk, ..Default::default() }
+    }
+}
+
+#[test]
+fn test_current_clocks() {
+    let mut table: crate::bindings::gpu_metrics_v1_3 = unsafe { core::mem::zeroed() };
+    table.current_gfxclk = 2500;
+    table.current_uclk = u16::MAX;
+
+    let clocks = CurrentClocks::from_gpu_metrics(&GpuMetrics::V1_3(table));
+
+    assert_eq!(clocks.gfx, Some(2500));
+    assert_eq!(clocks.mem, None);
+
+    let clocks = clocks.or(CurrentClocks::parse_pp_dpm([
+        Some("0: 500Mhz *\n"),
+        Some("0: 96Mhz \n1: 1000Mhz *\n"),
+        None,
+        None,
+        None,
+        None,
+    ]));
+
+    assert_eq!(clocks.gfx, Some(2500));
+    assert_eq!(clocks.mem, Some(1000));
+    assert_eq!(clocks.fclk, None);
+    assert!(!clocks.is_complete());
+}
+
+#[test]
+fn test_video_clocks() {
+    let mut table: crate::bindings::gpu_metrics_v2_2 = unsafe { core::mem::zeroed() };
+    table.current_vclk = 0;
+    table.current_dclk = u16::MAX;
+
+    let clocks = VideoClocks::from_gpu_metrics(&GpuMetrics::V2_2(table));
+
+    assert_eq!(clocks.vclk, Some(0));
+    assert_eq!(clocks.dclk, None);
+    assert!(!clocks.is_active());
+}
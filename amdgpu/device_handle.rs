@@ -29,7 +29,46 @@ use bindings::{
 };
 use core::mem::{size_of, MaybeUninit};
 
-pub struct DeviceHandle(pub(crate) DEVICE_HANDLE, pub(crate) i32);
+/// Handle of the libdrm_amdgpu device, deinitialized on [Drop].
+///
+/// # Sharing between threads
+/// The queries (`amdgpu_query_*`, `AMDGPU_INFO_*`, [DeviceHandle::query_sensor],
+/// [DeviceHandle::memory_info], ...) are stateless ioctls on the fd or copies of the
+/// device information cached by libdrm at initialization, so they are safe to call concurrently
+/// through `&DeviceHandle`.
+/// libdrm_amdgpu guards its own mutable state (BO tables, the device list) with mutexes.  
+/// Methods that write sysfs (e.g. [DeviceHandle::set_power_cap_uw], [DeviceHandle::set_performance_level])
+/// change the state of the whole GPU, concurrent writes are not serialized and the last one wins.
+///
+/// Use [DeviceHandle::into_shared] to share one handle instead of opening the device per thread.
+///
+/// ```no_run
+/// use libdrm_amdgpu_sys::AMDGPU::{DeviceHandle, SENSOR_INFO::SENSOR_TYPE};
+/// let amdgpu_dev = {
+///     use std::fs::File;
+///     use std::os::fd::IntoRawFd;
+///
+///     let fd = File::open("/dev/dri/renderD128").unwrap();
+///
+///     DeviceHandle::init(fd.into_raw_fd()).unwrap().0.into_shared()
+/// };
+/// let handles: Vec<_> = (0..4).map(|_| {
+///     let amdgpu_dev = amdgpu_dev.clone();
+///
+///     std::thread::spawn(move || {
+///         let load = amdgpu_dev.query_sensor(SENSOR_TYPE::GPU_LOAD);
+///         let vram = amdgpu_dev.memory_info().map(|m| m.vram.heap_usage);
+///
+///         (load, vram)
+///     })
+/// }).collect();
+///
+/// for h in handles {
+///     println!("{:?}", h.join().unwrap());
+/// }
+/// // the device is deinitialized when the last `Arc` is dropped
+/// ```
+pub struct DeviceHandle(pub(crate) DEVICE_HANDLE, pub(crate) i32, bool /* owns fd */);
 
 unsafe impl Send for DeviceHandle {}
 unsafe impl Sync for DeviceHandle {}
@@ -42,37 +81,98 @@ impl DeviceHandle {
     /// Example of `fd`: `/dev/dri/renderD128`, `/dev/dri/by-path/pci-{[PCI::BUS]}-render`  
     /// It may require a write option (`std::fs::OpenOptions::new().read(true).write(true)`)
     /// for GUI context.  
-    /// ref: <https://gitlab.freedesktop.org/mesa/mesa/-/issues/2424>
+    /// ref: <https://gitlab.freedesktop.org/mesa/mesa/-/issues/2424>  
+    /// With the `dlopen` feature, returns `Err(-ELIBACC)` if libdrm_amdgpu could not be loaded.
     pub fn init(fd: i32) -> Result<(Self, u32, u32), i32> {
         unsafe {
             let mut amdgpu_dev: MaybeUninit<amdgpu_device_handle> = MaybeUninit::zeroed();
             let mut major: MaybeUninit<u32> = MaybeUninit::zeroed();
             let mut minor: MaybeUninit<u32> = MaybeUninit::zeroed();
 
-            let r = bindings::amdgpu_device_initialize(
+            let r = ffi::amdgpu_device_initialize(
                 fd,
                 major.as_mut_ptr(),
                 minor.as_mut_ptr(),
                 amdgpu_dev.as_mut_ptr(),
             );
 
-            let [major, minor] = [major.assume_init(), minor.assume_init()];
-            let amdgpu_dev = Self(amdgpu_dev.assume_init(), fd);
-
             query_error!(r);
 
+            let [major, minor] = [major.assume_init(), minor.assume_init()];
+            let amdgpu_dev = Self(amdgpu_dev.assume_init(), fd, false);
+
             Ok((amdgpu_dev, major, minor))
         }
     }
 
+    /// Open the device node (e.g. `/dev/dri/renderD128`) with read+write access,
+    /// falling back to read-only on `EACCES`, and initialize it.  
+    /// The fd is owned by the handle and closed when the handle is dropped.
+    #[cfg(feature = "std")]
+    pub fn open<P: AsRef<std::path::Path>>(path: P) -> Result<(Self, u32, u32), DeviceInitError> {
+        use std::fs::{File, OpenOptions};
+        use std::os::fd::{AsRawFd, IntoRawFd};
+
+        if !crate::libdrm_amdgpu_available() {
+            return Err(DeviceInitError::LibraryNotFound);
+        }
+
+        let path = path.as_ref();
+        let file = match OpenOptions::new().read(true).write(true).open(path) {
+            Err(e) if e.kind() == std::io::ErrorKind::PermissionDenied => File::open(path),
+            r => r,
+        }.map_err(DeviceInitError::Open)?;
+
+        let (mut amdgpu_dev, major, minor) = Self::init(file.as_raw_fd())
+            .map_err(|r| DeviceInitError::Init(r.into()))?;
+
+        amdgpu_dev.2 = true;
+        let _ = file.into_raw_fd();
+
+        Ok((amdgpu_dev, major, minor))
+    }
+
+    /// Resolve the PCI bus to its render node and [DeviceHandle::open] it.  
+    /// The render node is looked up in `/dev/dri/by-path/`, then in `<device>/drm/` of sysfs
+    /// if the by-path symlink is missing.
+    #[cfg(feature = "std")]
+    pub fn open_by_pci_bus(bus: PCI::BUS_INFO) -> Result<(Self, u32, u32), DeviceInitError> {
+        let path = bus.get_drm_render_path().map_err(DeviceInitError::Open)?;
+
+        Self::open(path)
+    }
+
     fn deinit(&self) -> Result<i32, i32> {
-        let r = unsafe { bindings::amdgpu_device_deinitialize(self.0) };
+        // a NULL handle was never initialized, libdrm_amdgpu would dereference it
+        let r = if self.0.is_null() {
+            0
+        } else {
+            unsafe { ffi::amdgpu_device_deinitialize(self.0) }
+        };
+
+        if self.2 {
+            unsafe { libc::close(self.1) };
+        }
 
         query_error!(r);
 
         Ok(r)
     }
 
+    /// Wrap in [std::sync::Arc] to share the handle between threads,
+    /// see [DeviceHandle#sharing-between-threads]
+    #[cfg(feature = "std")]
+    pub fn into_shared(self) -> std::sync::Arc<Self> {
+        std::sync::Arc::new(self)
+    }
+
+    /// Deinitialize the device handle, and return the error instead of ignoring it in [Drop].
+    pub fn try_deinit(self) -> Result<(), i32> {
+        let dev = core::mem::ManuallyDrop::new(self);
+
+        dev.deinit().map(|_| ())
+    }
+
     pub fn get_fd(&self) -> i32 {
         self.1
     }
@@ -81,7 +181,7 @@ impl DeviceHandle {
     #[deprecated(since = "0.1.3", note = "superseded by `get_drm_version_struct`")]
     pub fn get_drm_version(&self) -> Result<(i32, i32, i32), ()> {
         let fd = self.1;
-        let drm_ver_ptr = unsafe { bindings::drmGetVersion(fd) };
+        let drm_ver_ptr = unsafe { ffi::drmGetVersion(fd) };
 
         if drm_ver_ptr.is_null() {
             return Err(());
@@ -93,7 +193,7 @@ impl DeviceHandle {
             (*drm_ver_ptr).version_patchlevel,
         ) };
 
-        unsafe { bindings::drmFreeVersion(drm_ver_ptr) }
+        unsafe { ffi::drmFreeVersion(drm_ver_ptr) }
 
         Ok(ver)
     }
@@ -103,27 +203,64 @@ impl DeviceHandle {
         drmVersion::get(self.1)
     }
 
+    /// Check if the DRM driver name of the fd is "amdgpu"
+    #[cfg(feature = "std")]
+    pub fn is_amdgpu(&self) -> bool {
+        self.get_drm_version_struct().is_ok_and(|ver| ver.is_amdgpu())
+    }
+
     /// Returns the result of reading the register at the specified offset.
     /// If the offset is not allowed, returns `Err(i32)`.
     pub fn read_mm_registers(&self, offset: u32) -> Result<u32, i32> {
-        unsafe {
-            let mut out: MaybeUninit<u32> = MaybeUninit::zeroed();
+        let mut out = [0u32; 1];
+
+        self.read_mm_registers_to_slice(
+            offset,
+            0xFFFF_FFFF, // instance mask, full mask
+            &mut out,
+        )?;
+
+        Ok(out[0])
+    }
 
-            let r = bindings::amdgpu_read_mm_registers(
+    /// Read `count` consecutive registers from the specified offset in a single call.  
+    /// The kernel allows up to 128 registers per call, and only registers in the allowed list.  
+    /// `instance_mask`: `0xFFFF_FFFF` for all instances, or [AMDGPU::mmr_instance_mask]
+    /// (ref: `AMDGPU_INFO_MMR_SE_INDEX_SHIFT`, `AMDGPU_INFO_MMR_SH_INDEX_SHIFT`)
+    #[cfg(feature = "std")]
+    pub fn read_mm_registers_range(
+        &self,
+        offset: u32,
+        count: u32,
+        instance_mask: u32,
+    ) -> Result<Vec<u32>, i32> {
+        let mut out = vec![0u32; count as usize];
+
+        self.read_mm_registers_to_slice(offset, instance_mask, &mut out)?;
+
+        Ok(out)
+    }
+
+    pub(crate) fn read_mm_registers_to_slice(
+        &self,
+        offset: u32,
+        instance_mask: u32,
+        out: &mut [u32],
+    ) -> Result<(), i32> {
+        let r = unsafe {
+            ffi::amdgpu_read_mm_registers(
                 self.0,
                 offset, // DWORD offset
-                1, // count
-                0xFFFF_FFFF, // instance mask, full mask
+                out.len() as u32, // count
+                instance_mask,
                 0, // flags
                 out.as_mut_ptr(),
-            );
-
-            let out = out.assume_init();
+            )
+        };
 
-            query_error!(r);
+        query_error!(r);
 
-            Ok(out)
-        }
+        Ok(())
     }
 
     /// From libdrm-2.4.114, it returns the default name ("AMD Radeon Graphics")
@@ -134,7 +271,7 @@ impl DeviceHandle {
     pub fn get_marketing_name(&self) -> Result<String, std::str::Utf8Error> {
         use core::ffi::CStr;
 
-        let mark_name = unsafe { bindings::amdgpu_get_marketing_name(self.0) };
+        let mark_name = unsafe { ffi::amdgpu_get_marketing_name(self.0) };
 
         if mark_name.is_null() {
             eprintln!("libdrm_amdgpu_sys: ASIC not found in amdgpu.ids");
@@ -147,30 +284,35 @@ impl DeviceHandle {
     }
 
     /// Returns the default marketing name ("AMD Radeon Graphics") 
-    /// when the device name is not available.
+    /// when the device name is not available.  
+    /// If the `amdgpu.ids` installed with libdrm is stale, the name is looked up in
+    /// the `amdgpu.ids` bundled with this crate ([AMDGPU::find_device_name]).
     #[cfg(feature = "std")]
     pub fn get_marketing_name_or_default(&self) -> String {
         use core::ffi::CStr;
+        use AMDGPU::GPU_INFO;
 
-        let mark_name_ptr = unsafe { bindings::amdgpu_get_marketing_name(self.0) };
+        let mark_name_ptr = unsafe { ffi::amdgpu_get_marketing_name(self.0) };
 
-        if mark_name_ptr.is_null() {
-            return AMDGPU::DEFAULT_DEVICE_NAME.to_string();
-        }
-
-        let c_str = unsafe { CStr::from_ptr(mark_name_ptr) };
+        let name = if mark_name_ptr.is_null() {
+            None
+        } else {
+            unsafe { CStr::from_ptr(mark_name_ptr) }.to_str().ok()
+        };
 
-        match c_str.to_str() {
-            Ok(name) => name,
-            Err(_) => AMDGPU::DEFAULT_DEVICE_NAME,
-        }.to_string()
+        match name {
+            Some(name) if name != AMDGPU::DEFAULT_DEVICE_NAME => name.to_string(),
+            _ => self.device_info().ok()
+                .and_then(|info| info.find_device_name())
+                .unwrap_or(AMDGPU::DEFAULT_DEVICE_NAME.to_string()),
+        }
     }
 
     pub fn query_gpu_info(&self) -> Result<amdgpu_gpu_info, i32> {
         unsafe {
             let mut gpu_info: MaybeUninit<amdgpu_gpu_info> = MaybeUninit::zeroed();
 
-            let r = bindings::amdgpu_query_gpu_info(self.0, gpu_info.as_mut_ptr());
+            let r = ffi::amdgpu_query_gpu_info(self.0, gpu_info.as_mut_ptr());
 
             let gpu_info = gpu_info.assume_init();
 
@@ -184,7 +326,7 @@ impl DeviceHandle {
         unsafe {
             let mut gds_info: MaybeUninit<amdgpu_gds_resource_info> = MaybeUninit::zeroed();
 
-            let r = bindings::amdgpu_query_gds_info(self.0, gds_info.as_mut_ptr());
+            let r = ffi::amdgpu_query_gds_info(self.0, gds_info.as_mut_ptr());
 
             let gds_info = gds_info.assume_init();
 
@@ -194,11 +336,12 @@ impl DeviceHandle {
         }
     }
 
+    /// Query the information held by libdrm_amdgpu, not the kernel (`amdgpu_query_sw_info`)
     pub fn query_sw_info(&self, info: amdgpu_sw_info) -> Result<u32, i32> {
         unsafe {
             let mut val: MaybeUninit<u32> = MaybeUninit::zeroed();
 
-            let r = bindings::amdgpu_query_sw_info(
+            let r = ffi::amdgpu_query_sw_info(
                 self.0,
                 info as u32,
                 val.as_mut_ptr() as *mut ::core::ffi::c_void,
@@ -212,11 +355,24 @@ impl DeviceHandle {
         }
     }
 
+    /// Query `AMDGPU_INFO_*` directly via `amdgpu_query_info`, for IDs not yet wrapped by this crate.
+    /// Prefer the typed methods (e.g. [DeviceHandle::device_info]) when available.  
+    /// The buffer is zero-initialized, and the kernel writes at most `size_of::<T>()` bytes.  
+    /// Queries requiring extra input (`AMDGPU_INFO_SENSOR`, `AMDGPU_INFO_FW_VERSION`, ...) are not supported.
+    ///
+    /// # Safety
+    /// `T` must match the layout and size the kernel expects for `info_id`
+    /// (e.g. a `#[repr(C)]` struct from `amdgpu_drm.h`, or `u32`/`u64`),
+    /// and the all-zero bit pattern must be a valid value of `T`.
+    pub unsafe fn query_info_raw<T>(&self, info_id: u32) -> Result<T, i32> {
+        self.query(info_id)
+    }
+
     pub(crate) fn query<T>(&self, info_id: ::core::ffi::c_uint) -> Result<T, i32> {
         unsafe {
             let mut dev: MaybeUninit<T> = MaybeUninit::zeroed();
 
-            let r = bindings::amdgpu_query_info(
+            let r = ffi::amdgpu_query_info(
                 self.0,
                 info_id,
                 size_of::<T>() as u32,
@@ -235,6 +391,17 @@ impl DeviceHandle {
         Self::query(self, AMDGPU_INFO_DEV_INFO)
     }
 
+    /// [AMDGPU::ASIC_NAME] from the family and `external_rev` of [Self::device_info]
+    pub fn get_asic_name(&self) -> Result<AMDGPU::ASIC_NAME, i32> {
+        use AMDGPU::GPU_INFO;
+
+        Ok(self.device_info()?.get_asic_name())
+    }
+
+    pub fn get_chip_class(&self) -> Result<AMDGPU::CHIP_CLASS, i32> {
+        Ok(self.get_asic_name()?.chip_class())
+    }
+
     /// Note: `usable_heap_size` equal `real_size - pin_size - reserved_size`, is not fixed.
     pub fn vram_gtt_info(&self) -> Result<drm_amdgpu_info_vram_gtt, i32> {
         // return 
@@ -262,7 +429,8 @@ impl DeviceHandle {
     }
 
     /// AMDGPU driver returns invalid [drm_amdgpu_info_vce_clock_table].
-    /// ref: <https://gitlab.freedesktop.org/drm/amd/-/issues/2391>
+    /// ref: <https://gitlab.freedesktop.org/drm/amd/-/issues/2391>  
+    /// Use [DeviceHandle::vce_clock_info_checked] to filter out the invalid entries.
     pub fn vce_clock_info(&self) -> Result<drm_amdgpu_info_vce_clock_table, i32> {
         Self::query(self, AMDGPU_INFO_VCE_CLOCK_TABLE)
     }
@@ -282,10 +450,62 @@ impl DeviceHandle {
         Self::query(self, AMDGPU_INFO_NUM_EVICTIONS)
     }
 
+    /// Number of GPU resets that lost the VRAM contents
     pub fn vram_lost_counter(&self) -> Result<u32, i32> {
         Self::query(self, AMDGPU_INFO_VRAM_LOST_COUNTER)
     }
 
+    /// Max/used UVD session handles (`AMDGPU_INFO_NUM_HANDLES`).  
+    /// The handles are used by UVD decode sessions and released when the session is destroyed.
+    /// The kernel answers this query only for UVD on ASICs older than Polaris10,
+    /// and returns `Err(-ENODATA)` on Polaris10 and later (unsupported, not a failure).
+    pub fn num_handles_info(&self) -> Result<bindings::drm_amdgpu_info_num_handles, i32> {
+        use bindings::{AMDGPU_HW_IP_UVD, AMDGPU_INFO_NUM_HANDLES, DRM_AMDGPU_INFO, drm_amdgpu_info};
+        use core::ptr;
+
+        unsafe {
+            let mut handles: MaybeUninit<bindings::drm_amdgpu_info_num_handles> = MaybeUninit::zeroed();
+            let mut request: MaybeUninit<drm_amdgpu_info> = MaybeUninit::zeroed();
+
+            {
+                let ptr = request.as_mut_ptr();
+
+                ptr::addr_of_mut!((*ptr).return_pointer).write(handles.as_mut_ptr() as u64);
+                ptr::addr_of_mut!((*ptr).return_size)
+                    .write(size_of::<bindings::drm_amdgpu_info_num_handles>() as u32);
+                ptr::addr_of_mut!((*ptr).query).write(AMDGPU_INFO_NUM_HANDLES);
+
+                // `amdgpu_query_info` can not set the IP type, the kernel only accepts UVD
+                ptr::addr_of_mut!((*ptr).__bindgen_anon_1.query_hw_ip.type_).write(AMDGPU_HW_IP_UVD);
+            }
+
+            let r = ffi::drmCommandWrite(
+                self.1,
+                DRM_AMDGPU_INFO as u64,
+                request.as_mut_ptr() as *mut ::core::ffi::c_void,
+                size_of::<drm_amdgpu_info>() as u64,
+            );
+
+            let (_, handles) = (request.assume_init(), handles.assume_init());
+
+            query_error!(r);
+
+            Ok(handles)
+        }
+    }
+
+    /// Number of UVD session handles in use.  
+    /// Returns `Err(-ENODATA)` on Polaris10 and later, see [DeviceHandle::num_handles_info]
+    pub fn num_uvd_handles_in_use(&self) -> Result<u64, i32> {
+        Ok(self.num_handles_info()?.uvd_used_handles as u64)
+    }
+
+    /// Max number of UVD session handles.  
+    /// Returns `Err(-ENODATA)` on Polaris10 and later, see [DeviceHandle::num_handles_info]
+    pub fn max_uvd_handles(&self) -> Result<u64, i32> {
+        Ok(self.num_handles_info()?.uvd_max_handles as u64)
+    }
+
     /// Get [PCI::BUS_INFO]
     pub fn get_pci_bus_info(&self) -> Result<PCI::BUS_INFO, i32> {
         PCI::BUS_INFO::drm_get_device2(self.1)
@@ -357,6 +577,69 @@ impl DeviceHandle {
         self.get_min_max_gpu_clock_from_sysfs(sysfs_path)
     }
 
+    /// GPU activity (%) from sysfs (`gpu_busy_percent`), clamped to `0..=100`.  
+    /// Returns `None` if the node is missing.
+    #[cfg(feature = "std")]
+    pub fn get_gpu_busy_percent(&self) -> Option<u8> {
+        let sysfs_path = self.get_sysfs_path().ok()?;
+
+        Self::get_busy_percent_from_sysfs(sysfs_path.join("gpu_busy_percent"))
+    }
+
+    /// Memory controller activity (%) from sysfs (`mem_busy_percent`), clamped to `0..=100`.  
+    /// Returns `None` if the node is missing.
+    #[cfg(feature = "std")]
+    pub fn get_mem_busy_percent(&self) -> Option<u8> {
+        let sysfs_path = self.get_sysfs_path().ok()?;
+
+        Self::get_busy_percent_from_sysfs(sysfs_path.join("mem_busy_percent"))
+    }
+
+    /// VRAM vendor from sysfs (`mem_info_vram_vendor`), e.g. "samsung", "hynix", "micron"
+    #[cfg(feature = "std")]
+    pub fn get_vram_vendor(&self) -> Option<String> {
+        let sysfs_path = self.get_sysfs_path().ok()?;
+
+        Self::get_vram_vendor_from_sysfs(sysfs_path)
+    }
+
+    #[cfg(feature = "std")]
+    pub(crate) fn get_vram_vendor_from_sysfs<P: Into<PathBuf>>(sysfs_path: P) -> Option<String> {
+        let s = std::fs::read_to_string(sysfs_path.into().join("mem_info_vram_vendor")).ok()?;
+
+        Some(s.trim_end().to_string())
+    }
+
+    /// 64-bit ASIC serial number from sysfs (`unique_id`).  
+    /// Returns `None` if unsupported (e.g. most Navi1x).
+    /// There is no info query for it, so sysfs is the only source.
+    #[cfg(feature = "std")]
+    pub fn get_unique_id(&self) -> Option<u64> {
+        let sysfs_path = self.get_sysfs_path().ok()?;
+
+        Self::get_unique_id_from_sysfs(sysfs_path)
+    }
+
+    /// [Self::get_unique_id] as a zero-padded hex string (16 digits)
+    #[cfg(feature = "std")]
+    pub fn get_unique_id_string(&self) -> Option<String> {
+        self.get_unique_id().map(|id| format!("{id:016x}"))
+    }
+
+    #[cfg(feature = "std")]
+    fn get_unique_id_from_sysfs<P: Into<PathBuf>>(sysfs_path: P) -> Option<u64> {
+        let s = std::fs::read_to_string(sysfs_path.into().join("unique_id")).ok()?;
+
+        u64::from_str_radix(s.trim(), 16).ok()
+    }
+
+    #[cfg(feature = "std")]
+    pub(crate) fn get_busy_percent_from_sysfs<P: Into<PathBuf>>(path: P) -> Option<u8> {
+        let val = AMDGPU::parse_hwmon::<u32, _>(path)?;
+
+        Some(val.min(100) as u8)
+    }
+
     /// 
     #[cfg(feature = "std")]
     pub fn get_sysfs_path(&self) -> Result<PathBuf, i32> {
@@ -371,6 +654,86 @@ impl DeviceHandle {
         self.get_pci_bus_info().ok()?.get_hwmon_path()
     }
 
+    /// Read the sysfs node `name` (relative to [DeviceHandle::get_sysfs_path]),
+    /// with the trailing newline removed.  
+    /// This is a best-effort escape hatch for the nodes without typed accessors,
+    /// the node names and formats depend on the kernel version and the ASIC.
+    #[cfg(feature = "std")]
+    pub fn get_sysfs_attr(&self, name: &str) -> std::io::Result<String> {
+        let path = self.sysfs_attr_path(name)?;
+        let s = std::fs::read_to_string(path)?;
+
+        Ok(s.trim_end_matches('\n').to_string())
+    }
+
+    /// [DeviceHandle::get_sysfs_attr] parsed as `T`, `ErrorKind::InvalidData` if parsing fails
+    #[cfg(feature = "std")]
+    pub fn get_sysfs_attr_parsed<T: std::str::FromStr>(&self, name: &str) -> std::io::Result<T> {
+        self.get_sysfs_attr(name)?
+            .trim()
+            .parse::<T>()
+            .map_err(|_| std::io::Error::new(std::io::ErrorKind::InvalidData, "failed to parse"))
+    }
+
+    /// Write `value` to the sysfs node `name`, most nodes require root privileges.  
+    /// See [DeviceHandle::get_sysfs_attr]. Writing to an unknown node can change the driver state.
+    #[cfg(feature = "std")]
+    pub fn set_sysfs_attr(&self, name: &str, value: &str) -> std::io::Result<()> {
+        let path = self.sysfs_attr_path(name)?;
+
+        std::fs::write(path, value)
+    }
+
+    #[cfg(feature = "std")]
+    fn sysfs_attr_path(&self, name: &str) -> std::io::Result<PathBuf> {
+        use std::io;
+
+        let sysfs_path = self.get_sysfs_path()
+            .map_err(|_| io::Error::from(io::ErrorKind::NotFound))?;
+
+        Self::join_sysfs_attr(sysfs_path, name)
+    }
+
+    /// `name` must be relative and stay under the device directory
+    #[cfg(feature = "std")]
+    fn join_sysfs_attr(sysfs_path: PathBuf, name: &str) -> std::io::Result<PathBuf> {
+        use std::path::{Component, Path};
+
+        let is_normal = |c: Component| matches!(c, Component::Normal(_) | Component::CurDir);
+
+        if name.is_empty() || !Path::new(name).components().all(is_normal) {
+            return Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, "invalid sysfs node name"));
+        }
+
+        Ok(sysfs_path.join(name))
+    }
+
+    /// Trigger a GPU reset, for testing recovery paths. Requires root privileges.  
+    /// Reads debugfs `amdgpu_gpu_recover` (driver GPU recovery), or writes `1` to
+    /// the PCI `reset` node of sysfs if debugfs is not available.
+    ///
+    /// **Danger**: All processes using the GPU lose their contexts, the display may go black,
+    /// and VRAM contents may be lost ([DeviceHandle::vram_lost_counter] is incremented).
+    /// The `DeviceHandle` (and any contexts created from it) should be considered invalid afterward,
+    /// reopen the device.
+    #[cfg(feature = "std")]
+    pub fn reset_gpu(&self) -> std::io::Result<()> {
+        use std::io;
+
+        let bus = self.get_pci_bus_info()
+            .map_err(|_| io::Error::from(io::ErrorKind::NotFound))?;
+        let r = match bus.get_debug_dri_path() {
+            Ok(debug_path) => std::fs::read(debug_path.join("amdgpu_gpu_recover")).map(|_| ()),
+            Err(_) => std::fs::write(bus.get_sysfs_path().join("reset"), "1"),
+        };
+
+        r.map_err(|e| if e.kind() == io::ErrorKind::PermissionDenied {
+            io::Error::new(io::ErrorKind::PermissionDenied, "GPU reset requires root privileges")
+        } else {
+            e
+        })
+    }
+
     /// ref: drivers/gpu/drm/amd/pm/swsmu/smu13/aldebaran_ppt.c
     /// ref: <https://github.com/RadeonOpenCompute/rocm_smi_lib/blob/master/python_smi_tools/rocm_smi.py>
     #[cfg(feature = "std")]
@@ -380,6 +743,25 @@ impl DeviceHandle {
         power_cap.check_if_secondary_die()
     }
 
+    /// Check if the device is an SR-IOV virtual function (VF).  
+    /// Uses the virtualization mode of `ids_flags`, and falls back to the `physfn` link in sysfs
+    /// for older kernels.  
+    /// See [AMDGPU::GPU_INFO::is_virtual_function] for the features typically blocked on a VF.
+    pub fn is_virtual_function(&self) -> bool {
+        use AMDGPU::GPU_INFO;
+
+        if self.device_info().map(|info| info.is_virtual_function()).unwrap_or(false) {
+            return true;
+        }
+
+        #[cfg(feature = "std")]
+        if let Ok(sysfs_path) = self.get_sysfs_path() {
+            return sysfs_path.join("physfn").exists();
+        }
+
+        false
+    }
+
     #[cfg(feature = "std")]
     pub fn get_min_max_link_info_from_dpm(&self) -> Option<[PCI::LINK; 2]> {
         let pci_bus = self.get_pci_bus_info().ok()?;
@@ -400,11 +782,59 @@ impl DeviceHandle {
 
         pci_bus.get_max_system_link()
     }
+
+    /// Returns `None` if unsupported. See [PCI::PcieMargining] for the kernel dependency.
+    #[cfg(feature = "std")]
+    pub fn get_pcie_margining(&self) -> Option<PCI::PcieMargining> {
+        let pci_bus = self.get_pci_bus_info().ok()?;
+
+        pci_bus.get_pcie_margining()
+    }
+}
+
+/// Error of [DeviceHandle::open]
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub enum DeviceInitError {
+    /// libdrm/libdrm_amdgpu could not be loaded (`dlopen` feature)
+    LibraryNotFound,
+    /// Failed to open the device node
+    Open(std::io::Error),
+    /// `amdgpu_device_initialize` failed
+    Init(crate::AmdgpuError),
+}
+
+#[cfg(feature = "std")]
+impl std::fmt::Display for DeviceInitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::LibraryNotFound => write!(f, "libdrm_amdgpu not found"),
+            Self::Open(e) => write!(f, "Failed to open the device: {e}"),
+            Self::Init(e) => write!(f, "amdgpu_device_initialize failed: {e}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DeviceInitError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::LibraryNotFound => None,
+            Self::Open(e) => Some(e),
+            Self::Init(e) => Some(e),
+        }
+    }
 }
 
 impl Drop for DeviceHandle {
+    /// Errors are not propagated so as not to panic during unwinding (e.g. GPU reset, hot-unplug),
+    /// use [DeviceHandle::try_deinit] to handle them.
     fn drop(&mut self) {
-        self.deinit().unwrap();
+        #[allow(unused_variables)]
+        if let Err(r) = self.deinit() {
+            #[cfg(feature = "std")]
+            eprintln!("libdrm_amdgpu_sys: amdgpu_device_deinitialize failed: {r}");
+        }
     }
 }
 
@@ -418,7 +848,37 @@ impl drm_amdgpu_memory_info {
     }
 }
 
+/// Used for [DeviceHandle::query_sw_info], mirrors `enum amdgpu_sw_info` of `amdgpu.h` (libdrm).  
+/// libdrm defines only `amdgpu_sw_info_address32_hi` (as of libdrm-2.4.124).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(u32)]
 pub enum amdgpu_sw_info {
-    address32_hi = 0,
+    /// Upper 32 bits of the 32-bit address space (`address32_hi` of `amdgpu_device`),
+    /// where the VA range for 32-bit pointers is allocated
+    address32_hi = bindings::amdgpu_sw_info_amdgpu_sw_info_address32_hi,
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_join_sysfs_attr() {
+    let base = PathBuf::from("/sys/bus/pci/devices/0000:03:00.0");
+
+    assert_eq!(
+        DeviceHandle::join_sysfs_attr(base.clone(), "hwmon/hwmon0/name").unwrap(),
+        base.join("hwmon/hwmon0/name"),
+    );
+
+    for name in ["", "/etc/passwd", "../0000:04:00.0/reset"] {
+        assert!(DeviceHandle::join_sysfs_attr(base.clone(), name).is_err(), "{name}");
+    }
+}
+
+// needs the libdrm_amdgpu symbols to link, even though they are not called
+#[cfg(any(feature = "link-drm", feature = "dlopen"))]
+#[test]
+fn test_drop_null_handle() {
+    // the state left by a failed `amdgpu_device_initialize`
+    drop(DeviceHandle(core::ptr::null_mut(), -1, false));
+
+    assert_eq!(DeviceHandle(core::ptr::null_mut(), -1, false).try_deinit(), Ok(()));
 }
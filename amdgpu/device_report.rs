@@ -0,0 +1,222 @@
+use crate::AMDGPU::{AsicCapabilities, DeviceHandle, GPU_INFO, SensorSnapshot};
+use crate::AMDGPU::FW_VERSION::FW_TYPE;
+use crate::PCI;
+
+/// A comprehensive report of the device, for scripting and bug reports
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DeviceReport {
+    pub device: DeviceSummary,
+    pub memory: MemorySummary,
+    pub clocks: ClockSummary,
+    pub sensors: SensorSnapshot,
+    pub pcie: PcieSummary,
+    pub firmware: Vec<FirmwareSummary>,
+    pub capabilities: Option<AsicCapabilities>,
+}
+
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DeviceSummary {
+    pub marketing_name: String,
+    pub device_id: u32,
+    pub revision_id: u32,
+    pub family: String,
+    pub asic_name: String,
+    pub chip_class: String,
+    pub gfx_target_version: Option<String>,
+    pub is_apu: bool,
+    pub num_shader_engines: u32,
+    pub num_shader_arrays_per_engine: u32,
+    pub cu_active_number: u32,
+    pub rb_pipes: u32,
+}
+
+/// Byte
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MemorySummary {
+    pub vram_type: String,
+    pub vram_bit_width: u32,
+    pub vram_size: u64,
+    pub cpu_accessible_vram_size: u64,
+    pub gtt_size: u64,
+    pub resizable_bar: bool,
+}
+
+/// MHz
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ClockSummary {
+    pub gpu: Option<[u32; 2]>,
+    pub memory: Option<[u32; 2]>,
+}
+
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PcieSummary {
+    pub bus: String,
+    pub current_link: Option<PCI::LINK>,
+    pub max_link: Option<PCI::LINK>,
+}
+
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FirmwareSummary {
+    pub fw_type: String,
+    pub version: u32,
+    pub feature: u32,
+}
+
+impl DeviceReport {
+    const FW_LIST: [FW_TYPE; 18] = [
+        FW_TYPE::VCE,
+        FW_TYPE::UVD,
+        FW_TYPE::GMC,
+        FW_TYPE::GFX_ME,
+        FW_TYPE::GFX_PFP,
+        FW_TYPE::GFX_CE,
+        FW_TYPE::GFX_RLC,
+        FW_TYPE::GFX_MEC,
+        FW_TYPE::SMC,
+        FW_TYPE::SDMA,
+        FW_TYPE::SOS,
+        FW_TYPE::ASD,
+        FW_TYPE::VCN,
+        FW_TYPE::DMCU,
+        FW_TYPE::TA,
+        FW_TYPE::DMCUB,
+        FW_TYPE::TOC,
+        FW_TYPE::VPE,
+    ];
+
+    pub fn from_device(amdgpu_dev: &DeviceHandle) -> Result<Self, i32> {
+        let info = amdgpu_dev.device_info()?;
+        let asic = info.get_asic_name();
+
+        let device = DeviceSummary {
+            marketing_name: amdgpu_dev.get_marketing_name_or_default(),
+            device_id: info.device_id(),
+            revision_id: info.pci_rev_id(),
+            family: info.get_family_name().to_string(),
+            asic_name: asic.to_string(),
+            chip_class: info.get_chip_class().to_string(),
+            gfx_target_version: info.get_gfx_target_version().map(|v| v.to_string()),
+            is_apu: info.is_apu(),
+            num_shader_engines: info.max_se(),
+            num_shader_arrays_per_engine: info.max_sa_per_se(),
+            cu_active_number: info.cu_active_number(),
+            rb_pipes: info.rb_pipes(),
+        };
+
+        let memory = {
+            let mem = amdgpu_dev.memory_info()?;
+
+            MemorySummary {
+                vram_type: info.get_vram_type().to_string(),
+                vram_bit_width: info.vram_bit_width(),
+                vram_size: mem.vram.total_heap_size,
+                cpu_accessible_vram_size: mem.cpu_accessible_vram.total_heap_size,
+                gtt_size: mem.gtt.total_heap_size,
+                resizable_bar: mem.check_resizable_bar(),
+            }
+        };
+
+        let clocks = ClockSummary {
+            gpu: amdgpu_dev.get_min_max_gpu_clock().map(|(min, max)| [min, max]),
+            memory: amdgpu_dev.get_min_max_memory_clock().map(|(min, max)| [min, max]),
+        };
+
+        let sensors = amdgpu_dev.get_hwmon_path()
+            .map(SensorSnapshot::from_hwmon_path)
+            .unwrap_or_default();
+
+        let pcie = {
+            let bus = amdgpu_dev.get_pci_bus_info()?;
+
+            PcieSummary {
+                bus: bus.to_string(),
+                current_link: bus.get_current_link_info(),
+                max_link: bus.get_max_link_info(),
+            }
+        };
+
+        let firmware = Self::FW_LIST.iter().filter_map(|fw_type| {
+            let fw = amdgpu_dev.query_firmware_version(*fw_type, 0, 0).ok()?;
+
+            Some(FirmwareSummary {
+                fw_type: fw_type.to_string(),
+                version: fw.version,
+                feature: fw.feature,
+            })
+        }).collect();
+
+        Ok(Self {
+            device,
+            memory,
+            clocks,
+            sensors,
+            pcie,
+            firmware,
+            capabilities: Some(asic.capabilities()),
+        })
+    }
+
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+/// Error of [DeviceHandle::get_info_json]
+#[cfg(feature = "serde")]
+#[derive(Debug)]
+pub enum DeviceReportError {
+    Query(crate::AmdgpuError),
+    Json(serde_json::Error),
+}
+
+#[cfg(feature = "serde")]
+impl std::fmt::Display for DeviceReportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Query(e) => write!(f, "Query failed: {e}"),
+            Self::Json(e) => write!(f, "Serialization failed: {e}"),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl std::error::Error for DeviceReportError {}
+
+impl DeviceHandle {
+    pub fn get_device_report(&self) -> Result<DeviceReport, i32> {
+        DeviceReport::from_device(self)
+    }
+
+    /// Serialize [DeviceReport] to pretty JSON
+    #[cfg(feature = "serde")]
+    pub fn get_info_json(&self) -> Result<String, DeviceReportError> {
+        let report = self.get_device_report()
+            .map_err(|r| DeviceReportError::Query(r.into()))?;
+
+        report.to_json().map_err(DeviceReportError::Json)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_device_report_json() {
+    let report = DeviceReport {
+        capabilities: Some(crate::AMDGPU::ASIC_NAME::CHIP_NAVI21.capabilities()),
+        ..Default::default()
+    };
+    let json = report.to_json().unwrap();
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+    for key in ["device", "memory", "clocks", "sensors", "pcie", "firmware", "capabilities"] {
+        assert!(value.get(key).is_some(), "{key}");
+    }
+
+    assert_eq!(value["capabilities"]["rbplus_allowed"], serde_json::Value::Bool(true));
+}
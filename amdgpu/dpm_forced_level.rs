@@ -1,5 +1,7 @@
+use crate::AMDGPU::DeviceHandle;
 use std::io;
 use std::path::PathBuf;
+use std::str::FromStr;
 
 const SYSFS_NAME: &str = "power_dpm_force_performance_level";
 
@@ -17,33 +19,34 @@ pub enum DpmForcedLevel {
     PerfDeterminism,
 }
 
+/// Alias of [DpmForcedLevel]
+pub type PerformanceLevel = DpmForcedLevel;
+
+impl DeviceHandle {
+    /// Get the current level from sysfs (`power_dpm_force_performance_level`)
+    pub fn get_performance_level(&self) -> Option<DpmForcedLevel> {
+        let sysfs_path = self.get_sysfs_path().ok()?;
+
+        DpmForcedLevel::get_from_sysfs(sysfs_path).ok()
+    }
+
+    /// Set the level to sysfs (`power_dpm_force_performance_level`). Requires root privileges.  
+    /// [DpmForcedLevel::Manual] is required before forcing DPM levels
+    /// (e.g. [DeviceHandle::force_pcie_dpm_level]) or selecting a power profile.
+    pub fn set_performance_level(&self, level: DpmForcedLevel) -> io::Result<()> {
+        let sysfs_path = self.get_sysfs_path()
+            .map_err(|_| io::Error::from(io::ErrorKind::NotFound))?;
+
+        std::fs::write(sysfs_path.join(SYSFS_NAME), level.to_arg())
+    }
+}
+
 impl DpmForcedLevel {
     pub fn get_from_sysfs<P: Into<PathBuf>>(sysfs_path: P) -> io::Result<Self> {
         let sysfs_path = sysfs_path.into();
         let s = std::fs::read_to_string(sysfs_path.join(SYSFS_NAME))?;
 
-        let level = match s.get(0..4).unwrap_or("") {
-            "auto" => Self::Auto,
-            "low\n" => Self::Low,
-            "high" => Self::High,
-            "manu" => Self::Manual,
-            "prof" => {
-                const PRE_LEN: usize = "profile_".len();
-
-                match s.get(PRE_LEN..).unwrap_or("") {
-                    "standard\n" => Self::ProfileStandard,
-                    "peak\n" => Self::ProfilePeak,
-                    "min_sclk\n" => Self::ProfileMinSclk,
-                    "min_mclk\n" => Self::ProfileMinMclk,
-                    "exit\n" => Self::ProfileExit,
-                    _ => return Err(io::Error::other("Unknown Level")),
-                }
-            },
-            "perf" => Self::PerfDeterminism,
-            _ => return Err(io::Error::other("Unknown Level")),
-        };
-
-        Ok(level)
+        s.trim_end().parse().map_err(|_| io::Error::other("Unknown Level"))
     }
 
     pub const fn to_arg(&self) -> &str {
@@ -61,3 +64,48 @@ impl DpmForcedLevel {
         }
     }
 }
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseDpmForcedLevelError;
+
+impl FromStr for DpmForcedLevel {
+    type Err = ParseDpmForcedLevelError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let level = match s {
+            "auto" => Self::Auto,
+            "low" => Self::Low,
+            "high" => Self::High,
+            "manual" => Self::Manual,
+            "profile_standard" => Self::ProfileStandard,
+            "profile_peak" => Self::ProfilePeak,
+            "profile_min_sclk" => Self::ProfileMinSclk,
+            "profile_min_mclk" => Self::ProfileMinMclk,
+            "profile_exit" => Self::ProfileExit,
+            "perf_determinism" => Self::PerfDeterminism,
+            _ => return Err(ParseDpmForcedLevelError),
+        };
+
+        Ok(level)
+    }
+}
+
+#[test]
+fn test_dpm_forced_level_round_trip() {
+    for level in [
+        DpmForcedLevel::Auto,
+        DpmForcedLevel::Manual,
+        DpmForcedLevel::Low,
+        DpmForcedLevel::High,
+        DpmForcedLevel::ProfileStandard,
+        DpmForcedLevel::ProfileMinSclk,
+        DpmForcedLevel::ProfileMinMclk,
+        DpmForcedLevel::ProfilePeak,
+        DpmForcedLevel::ProfileExit,
+        DpmForcedLevel::PerfDeterminism,
+    ] {
+        assert_eq!(level.to_arg().parse(), Ok(level));
+    }
+
+    assert_eq!("profile_".parse::<DpmForcedLevel>(), Err(ParseDpmForcedLevelError));
+}
@@ -0,0 +1,222 @@
+use crate::AMDGPU::DeviceHandle;
+use crate::PCI;
+use std::io;
+use std::path::PathBuf;
+
+/// A level of DPM table (`pp_dpm_sclk`, `pp_dpm_mclk`, ...)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DpmLevel {
+    pub index: u8,
+    pub clock_mhz: u32,
+    /// The current level, marked with `*`
+    pub active: bool,
+}
+
+impl DeviceHandle {
+    /// Get all levels of the gpu core clock from sysfs (`pp_dpm_sclk`)
+    pub fn get_gpu_clock_levels(&self) -> Option<Vec<DpmLevel>> {
+        let sysfs_path = self.get_sysfs_path().ok()?;
+
+        DpmLevel::get_from_sysfs(sysfs_path.join("pp_dpm_sclk"))
+    }
+
+    /// Get all levels of the memory clock from sysfs (`pp_dpm_mclk`)
+    pub fn get_memory_clock_levels(&self) -> Option<Vec<DpmLevel>> {
+        let sysfs_path = self.get_sysfs_path().ok()?;
+
+        DpmLevel::get_from_sysfs(sysfs_path.join("pp_dpm_mclk"))
+    }
+
+    /// Get all levels of the VCN video clock from sysfs (`pp_dpm_vclk`).  
+    /// Only available on cards with VCN DPM (Navi1x and later).
+    pub fn get_vclk_levels(&self) -> Option<Vec<DpmLevel>> {
+        let sysfs_path = self.get_sysfs_path().ok()?;
+
+        DpmLevel::get_from_sysfs(sysfs_path.join("pp_dpm_vclk"))
+    }
+
+    /// Get all levels of the VCN decoder clock from sysfs (`pp_dpm_dclk`).  
+    /// Only available on cards with VCN DPM (Navi1x and later).
+    pub fn get_dclk_levels(&self) -> Option<Vec<DpmLevel>> {
+        let sysfs_path = self.get_sysfs_path().ok()?;
+
+        DpmLevel::get_from_sysfs(sysfs_path.join("pp_dpm_dclk"))
+    }
+
+    /// Get all levels of the data fabric clock from sysfs (`pp_dpm_fclk`).  
+    /// On APUs, the memory bandwidth tracks FCLK.
+    pub fn get_fclk_levels(&self) -> Option<Vec<DpmLevel>> {
+        let sysfs_path = self.get_sysfs_path().ok()?;
+
+        DpmLevel::get_from_sysfs(sysfs_path.join("pp_dpm_fclk"))
+    }
+
+    /// Get all levels of the SoC clock from sysfs (`pp_dpm_socclk`)
+    pub fn get_socclk_levels(&self) -> Option<Vec<DpmLevel>> {
+        let sysfs_path = self.get_sysfs_path().ok()?;
+
+        DpmLevel::get_from_sysfs(sysfs_path.join("pp_dpm_socclk"))
+    }
+
+    /// Get the min/max VCN video clock (MHz) from sysfs (`pp_dpm_vclk`)
+    pub fn get_min_max_vclk(&self) -> Option<(u32, u32)> {
+        DpmLevel::min_max_clock(&self.get_vclk_levels()?)
+    }
+
+    /// Get the min/max VCN decoder clock (MHz) from sysfs (`pp_dpm_dclk`)
+    pub fn get_min_max_dclk(&self) -> Option<(u32, u32)> {
+        DpmLevel::min_max_clock(&self.get_dclk_levels()?)
+    }
+}
+
+/// A level of PCIe DPM table (`pp_dpm_pcie`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PcieDpmLevel {
+    pub index: u8,
+    pub link: PCI::LINK,
+    pub active: bool,
+}
+
+impl DeviceHandle {
+    /// Get all levels of the PCIe link from sysfs (`pp_dpm_pcie`)
+    pub fn get_pcie_dpm_levels(&self) -> Option<Vec<PcieDpmLevel>> {
+        let sysfs_path = self.get_sysfs_path().ok()?;
+
+        PcieDpmLevel::get_from_sysfs(sysfs_path.join("pp_dpm_pcie"))
+    }
+
+    /// Force the PCIe DPM levels by bitmask (e.g. `0b10` for level 1) via `pp_dpm_pcie`.  
+    /// Requires root privileges and `power_dpm_force_performance_level` to be `manual`.
+    pub fn force_pcie_dpm_level(&self, mask: u32) -> io::Result<()> {
+        let sysfs_path = self.get_sysfs_path()
+            .map_err(|_| io::Error::from(io::ErrorKind::NotFound))?;
+
+        if mask == 0 {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "Empty mask"));
+        }
+
+        std::fs::write(sysfs_path.join("pp_dpm_pcie"), PcieDpmLevel::mask_to_command(mask))
+    }
+}
+
+impl PcieDpmLevel {
+    pub fn get_from_sysfs<P: Into<PathBuf>>(path: P) -> Option<Vec<Self>> {
+        crate::get_all_from_dpm(path, Self::parse_line)
+    }
+
+    /*
+        "0: 2.5GT/s, x1 "
+        "1: 8.0GT/s, x16 *"
+        "1: 16.0GT/s, x16 619Mhz *" (with the clock)
+    */
+    pub fn parse_line(s: &str) -> Option<Self> {
+        let (index, rest) = s.split_once(':')?;
+        let index = index.trim().parse::<u8>().ok()?;
+        let link = PCI::LINK::parse_dpm_line(s)?;
+        let active = rest.split_whitespace().any(|v| v.contains('*'));
+
+        Some(Self { index, link, active })
+    }
+
+    /// `0b101` -> `"0 2"`
+    fn mask_to_command(mask: u32) -> String {
+        let levels: Vec<String> = (0..u32::BITS)
+            .filter(|i| (mask >> i) & 0b1 == 1)
+            .map(|i| i.to_string())
+            .collect();
+
+        levels.join(" ")
+    }
+}
+
+impl DpmLevel {
+    pub fn get_from_sysfs<P: Into<PathBuf>>(path: P) -> Option<Vec<Self>> {
+        crate::get_all_from_dpm(path, Self::parse_line)
+    }
+
+    /// Returns `None` if `levels` is empty
+    pub fn min_max_clock(levels: &[Self]) -> Option<(u32, u32)> {
+        let min = levels.iter().map(|l| l.clock_mhz).min()?;
+        let max = levels.iter().map(|l| l.clock_mhz).max()?;
+
+        Some((min, max))
+    }
+
+    /// Clock of the active level (marked with `*`) in the contents of `pp_dpm_*`, MHz
+    pub fn active_clock(s: &str) -> Option<u32> {
+        s.lines()
+            .filter_map(Self::parse_line)
+            .find(|level| level.active)
+            .map(|level| level.clock_mhz)
+    }
+
+    /*
+        "0: 500Mhz *"
+        "1: 2615Mhz "
+        "0: 300Mhz 0.90V *" (with voltage)
+        "S: 19Mhz *" (deep sleep, skipped)
+    */
+    pub fn parse_line(s: &str) -> Option<Self> {
+        let (index, rest) = s.split_once(':')?;
+        let index = index.trim().parse::<u8>().ok()?;
+        let mut split = rest.split_whitespace();
+        let clock_mhz = split.next()?
+            .to_ascii_lowercase()
+            .strip_suffix("mhz")?
+            .parse::<u32>()
+            .ok()?;
+        let active = split.any(|v| v.contains('*'));
+
+        Some(Self { index, clock_mhz, active })
+    }
+}
+
+#[test]
+fn test_dpm_level_active_clock() {
+    assert_eq!(DpmLevel::active_clock("0: 500Mhz \n1: 2500Mhz *\n"), Some(2500));
+    assert_eq!(DpmLevel::active_clock("S: 19Mhz *\n0: 500Mhz \n"), None);
+}
+
+#[test]
+fn test_dpm_level_parse() {
+    assert_eq!(
+        DpmLevel::parse_line("0: 500Mhz *"),
+        Some(DpmLevel { index: 0, clock_mhz: 500, active: true }),
+    );
+    assert_eq!(
+        DpmLevel::parse_line("2: 2615Mhz "),
+        Some(DpmLevel { index: 2, clock_mhz: 2615, active: false }),
+    );
+    assert_eq!(
+        DpmLevel::parse_line("1: 1000MHz 0.90V *"),
+        Some(DpmLevel { index: 1, clock_mhz: 1000, active: true }),
+    );
+    assert_eq!(DpmLevel::parse_line("S: 19Mhz *"), None);
+
+    let levels: Vec<DpmLevel> = ["0: 1000Mhz *", "1: 25Mhz", "2: 1900Mhz"]
+        .iter()
+        .filter_map(|s| DpmLevel::parse_line(s))
+        .collect();
+
+    assert_eq!(DpmLevel::min_max_clock(&levels), Some((25, 1900)));
+    assert_eq!(DpmLevel::min_max_clock(&[]), None);
+}
+
+#[test]
+fn test_pcie_dpm_level_parse() {
+    let link = PCI::LINK { gen: 3, width: 16 };
+
+    assert_eq!(
+        PcieDpmLevel::parse_line("1: 8.0GT/s, x16 *"),
+        Some(PcieDpmLevel { index: 1, link, active: true }),
+    );
+    assert_eq!(
+        PcieDpmLevel::parse_line("1: 8.0GT/s, x16 619Mhz"),
+        Some(PcieDpmLevel { index: 1, link, active: false }),
+    );
+    assert_eq!(
+        PcieDpmLevel::parse_line("0: 2.5GT/s, x1"),
+        Some(PcieDpmLevel { index: 0, link: PCI::LINK { gen: 1, width: 1 }, active: false }),
+    );
+    assert_eq!(PcieDpmLevel::mask_to_command(0b101), "0 2");
+}
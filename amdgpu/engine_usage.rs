@@ -0,0 +1,151 @@
+use crate::AMDGPU::{DrmClientUsage, scan_drm_clients};
+use crate::PCI;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Cumulative busy time of the engines (`drm-engine-*` of fdinfo), ns
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EngineTimes {
+    pub gfx: u64,
+    pub compute: u64,
+    pub dma: u64,
+    pub dec: u64,
+    pub enc: u64,
+}
+
+impl EngineTimes {
+    fn saturating_sub(&self, prev: &Self) -> Self {
+        Self {
+            gfx: self.gfx.saturating_sub(prev.gfx),
+            compute: self.compute.saturating_sub(prev.compute),
+            dma: self.dma.saturating_sub(prev.dma),
+            dec: self.dec.saturating_sub(prev.dec),
+            enc: self.enc.saturating_sub(prev.enc),
+        }
+    }
+
+    fn add(&mut self, other: &Self) {
+        self.gfx += other.gfx;
+        self.compute += other.compute;
+        self.dma += other.dma;
+        self.dec += other.dec;
+        self.enc += other.enc;
+    }
+}
+
+impl From<&DrmClientUsage> for EngineTimes {
+    fn from(c: &DrmClientUsage) -> Self {
+        Self { gfx: c.gfx_ns, compute: c.compute_ns, dma: c.dma_ns, dec: c.dec_ns, enc: c.enc_ns }
+    }
+}
+
+/// Busy percentage of the engines over an interval, summed over all DRM clients.
+/// It can exceed 100% if the jobs run in parallel on multiple rings of the engine
+/// (e.g. compute queues, encode rings).
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct EngineUsage {
+    pub gfx: f64,
+    pub compute: f64,
+    pub dma: f64,
+    pub dec: f64,
+    pub enc: f64,
+}
+
+impl EngineUsage {
+    pub fn from_delta(delta: &EngineTimes, interval: Duration) -> Self {
+        let interval_ns = interval.as_nanos() as f64;
+        let percent = |ns: u64| if interval_ns == 0.0 { 0.0 } else { ns as f64 * 100.0 / interval_ns };
+
+        Self {
+            gfx: percent(delta.gfx),
+            compute: percent(delta.compute),
+            dma: percent(delta.dma),
+            dec: percent(delta.dec),
+            enc: percent(delta.enc),
+        }
+    }
+}
+
+/// Per-engine utilization of a device from the deltas of the `drm-engine-*` counters
+/// in `/proc/<pid>/fdinfo/*` of all processes.
+/// Only the processes whose fdinfo is readable are counted, root privileges are required
+/// for full visibility (see [crate::AMDGPU::list_gpu_processes]).
+/// The busy time of the clients closed between the samples is lost.
+#[derive(Debug, Clone)]
+pub struct EngineUsageTracker {
+    pdev: String,
+    /// (PID, `drm-client-id`)
+    last: HashMap<(u32, u64), EngineTimes>,
+    last_time: Instant,
+}
+
+impl EngineUsageTracker {
+    pub fn new(bus: &PCI::BUS_INFO) -> Self {
+        let pdev = bus.to_string();
+        let last = Self::collect(&pdev);
+
+        Self { pdev, last, last_time: Instant::now() }
+    }
+
+    fn collect(pdev: &str) -> HashMap<(u32, u64), EngineTimes> {
+        scan_drm_clients(pdev)
+            .iter()
+            .map(|(pid, c)| ((*pid, c.client_id), EngineTimes::from(c)))
+            .collect()
+    }
+
+    /// Sum of the increases since the previous sample,
+    /// a client that appeared after the previous sample counts from `0`
+    fn delta(
+        prev: &HashMap<(u32, u64), EngineTimes>,
+        cur: &HashMap<(u32, u64), EngineTimes>,
+    ) -> EngineTimes {
+        let mut delta = EngineTimes::default();
+
+        for (key, times) in cur {
+            let d = match prev.get(key) {
+                Some(prev) => times.saturating_sub(prev),
+                None => *times,
+            };
+
+            delta.add(&d);
+        }
+
+        delta
+    }
+
+    /// Sample the counters and return the usage since the previous sample (or [EngineUsageTracker::new])
+    pub fn sample(&mut self) -> EngineUsage {
+        let cur = Self::collect(&self.pdev);
+        let now = Instant::now();
+        let delta = Self::delta(&self.last, &cur);
+        let usage = EngineUsage::from_delta(&delta, now.duration_since(self.last_time));
+
+        self.last = cur;
+        self.last_time = now;
+
+        usage
+    }
+}
+
+#[test]
+fn test_engine_usage_delta() {
+    let prev = HashMap::from([
+        ((100, 1), EngineTimes { gfx: 1_000_000, ..Default::default() }),
+        ((200, 2), EngineTimes { dec: 500_000, ..Default::default() }),
+    ]);
+    let cur = HashMap::from([
+        ((100, 1), EngineTimes { gfx: 51_000_000, ..Default::default() }),
+        ((300, 3), EngineTimes { compute: 10_000_000, ..Default::default() }),
+    ]);
+    let delta = EngineUsageTracker::delta(&prev, &cur);
+
+    assert_eq!(delta, EngineTimes { gfx: 50_000_000, compute: 10_000_000, ..Default::default() });
+
+    let usage = EngineUsage::from_delta(&delta, Duration::from_millis(100));
+
+    assert_eq!(usage.gfx, 50.0);
+    assert_eq!(usage.compute, 10.0);
+    assert_eq!(usage.dec, 0.0);
+    assert_eq!(EngineUsage::from_delta(&delta, Duration::ZERO), EngineUsage::default());
+}
@@ -0,0 +1,98 @@
+use crate::AMDGPU::DeviceHandle;
+use core::time::Duration;
+
+/// Cumulative TTM counters (`AMDGPU_INFO_NUM_EVICTIONS`, `AMDGPU_INFO_NUM_BYTES_MOVED`,
+/// `AMDGPU_INFO_NUM_VRAM_CPU_PAGE_FAULTS`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EvictionCounters {
+    pub evictions: u64,
+    pub bytes_moved: u64,
+    pub vram_cpu_page_faults: u64,
+}
+
+/// Change of [EvictionCounters] over an interval
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct EvictionDelta {
+    pub evictions: u64,
+    pub bytes_moved: u64,
+    pub vram_cpu_page_faults: u64,
+    /// 0 if the interval is zero
+    pub evictions_per_sec: f64,
+    /// 0 if the interval is zero
+    pub bytes_moved_per_sec: f64,
+}
+
+impl EvictionCounters {
+    pub fn get(amdgpu_dev: &DeviceHandle) -> Result<Self, i32> {
+        Ok(Self {
+            evictions: amdgpu_dev.num_evictions()?,
+            bytes_moved: amdgpu_dev.num_bytes_moved()?,
+            vram_cpu_page_faults: amdgpu_dev.num_vram_cpu_page_faults()?,
+        })
+    }
+
+    /// The counters are reset on driver reload, so it saturates at 0.
+    pub fn delta(&self, prev: &Self, interval: Duration) -> EvictionDelta {
+        let evictions = self.evictions.saturating_sub(prev.evictions);
+        let bytes_moved = self.bytes_moved.saturating_sub(prev.bytes_moved);
+        let vram_cpu_page_faults = self.vram_cpu_page_faults.saturating_sub(prev.vram_cpu_page_faults);
+        let per_sec = |v: u64| {
+            let sec = interval.as_secs_f64();
+
+            if sec == 0.0 { 0.0 } else { v as f64 / sec }
+        };
+
+        EvictionDelta {
+            evictions,
+            bytes_moved,
+            vram_cpu_page_faults,
+            evictions_per_sec: per_sec(evictions),
+            bytes_moved_per_sec: per_sec(bytes_moved),
+        }
+    }
+}
+
+/// Keeps the last [EvictionCounters] to turn the cumulative counters into per-interval deltas,
+/// for detecting memory thrashing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EvictionTracker {
+    last: EvictionCounters,
+}
+
+impl EvictionTracker {
+    pub fn new(amdgpu_dev: &DeviceHandle) -> Result<Self, i32> {
+        Ok(Self { last: EvictionCounters::get(amdgpu_dev)? })
+    }
+
+    /// `interval` is the time elapsed since the previous sample (or [EvictionTracker::new]),
+    /// measured by the caller.
+    pub fn sample(
+        &mut self,
+        amdgpu_dev: &DeviceHandle,
+        interval: Duration,
+    ) -> Result<EvictionDelta, i32> {
+        let cur = EvictionCounters::get(amdgpu_dev)?;
+        let delta = cur.delta(&self.last, interval);
+
+        self.last = cur;
+
+        Ok(delta)
+    }
+
+    pub fn last(&self) -> EvictionCounters {
+        self.last
+    }
+}
+
+#[test]
+fn test_eviction_counters_delta() {
+    let prev = EvictionCounters { evictions: 10, bytes_moved: 1 << 20, vram_cpu_page_faults: 3 };
+    let cur = EvictionCounters { evictions: 30, bytes_moved: 5 << 20, vram_cpu_page_faults: 4 };
+    let delta = cur.delta(&prev, Duration::from_millis(500));
+
+    assert_eq!(delta.evictions, 20);
+    assert_eq!(delta.vram_cpu_page_faults, 1);
+    assert_eq!(delta.evictions_per_sec, 40.0);
+    assert_eq!(delta.bytes_moved_per_sec, (8 << 20) as f64);
+    assert_eq!(prev.delta(&cur, Duration::ZERO), EvictionDelta::default());
+}
@@ -43,6 +43,14 @@ pub enum FAMILY_NAME {
 
 impl From<u32> for FAMILY_NAME {
     fn from(family_id: u32) -> Self {
+        Self::from_u32(family_id)
+    }
+}
+
+impl FAMILY_NAME {
+    /// Convert the raw family id (e.g. `drm_amdgpu_info_device::family`) to [FAMILY_NAME].  
+    /// Returns [FAMILY_NAME::UNKNOWN] for unrecognized values.
+    pub const fn from_u32(family_id: u32) -> Self {
         match family_id {
             AMDGPU_FAMILY_SI => Self::SI,
             AMDGPU_FAMILY_CI => Self::CI,
@@ -62,18 +70,41 @@ impl From<u32> for FAMILY_NAME {
             AMDGPU_FAMILY_UNKNOWN | _ => Self::UNKNOWN,
         }
     }
-}
 
-impl FAMILY_NAME {
     /// Get [ASIC_NAME] from [FAMILY_NAME]
     pub fn asic_name(&self, chip_external_rev: u32) -> ASIC_NAME {
         ASIC_NAME::get(*self, chip_external_rev)
     }
 }
 
-#[cfg(feature = "std")]
-use std::fmt;
-#[cfg(feature = "std")]
+#[test]
+fn test_family_name_from_u32() {
+    for family in [
+        FAMILY_NAME::SI,
+        FAMILY_NAME::CI,
+        FAMILY_NAME::KV,
+        FAMILY_NAME::VI,
+        FAMILY_NAME::CZ,
+        FAMILY_NAME::AI,
+        FAMILY_NAME::RV,
+        FAMILY_NAME::NV,
+        FAMILY_NAME::VGH,
+        FAMILY_NAME::GC_11_0_0,
+        FAMILY_NAME::YC,
+        FAMILY_NAME::GC_11_0_1,
+        FAMILY_NAME::GC_10_3_6,
+        FAMILY_NAME::GC_10_3_7,
+        FAMILY_NAME::GC_11_5_0,
+    ] {
+        assert_eq!(FAMILY_NAME::from_u32(family as u32), family);
+    }
+
+    assert_eq!(FAMILY_NAME::from_u32(0xFFFF), FAMILY_NAME::UNKNOWN);
+    #[cfg(feature = "std")]
+    assert_eq!(FAMILY_NAME::from_u32(AMDGPU_FAMILY_NV).to_string(), "Navi (NV)");
+}
+
+use core::fmt;
 impl fmt::Display for FAMILY_NAME {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -0,0 +1,150 @@
+use crate::AMDGPU::DeviceHandle;
+use std::io;
+use std::path::PathBuf;
+
+/* ref: drivers/gpu/drm/amd/pm/amdgpu_pm.c */
+const FAN_CTRL_DIR: &str = "gpu_od/fan_ctrl";
+const FAN_CURVE: &str = "fan_curve";
+const FAN_ZERO_RPM_ENABLE: &str = "fan_zero_rpm_enable";
+const COMMIT: &str = "c";
+
+/// Fan curve from `gpu_od/fan_ctrl/fan_curve`.
+/// Supported on SMU v13.0.0/v13.0.7 (RDNA 3) and SMU v14.0.2/v14.0.3 (RDNA 4) with Linux Kernel 6.7 or later,
+/// and requires the OverDrive bit (`0x4000`) of `amdgpu.ppfeaturemask`.
+/// Writing a curve replaces the automatic fan control by the SMU with the custom curve.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FanCurve {
+    /// (temperature (Celsius), fan speed (%))
+    pub points: Vec<(i32, u8)>,
+    /// Celsius, `FAN_CURVE(hotspot temp)`
+    pub temp_range: Option<[i32; 2]>,
+    /// %, `FAN_CURVE(fan speed)`
+    pub speed_range: Option<[u8; 2]>,
+}
+
+impl DeviceHandle {
+    pub fn get_fan_curve(&self) -> io::Result<FanCurve> {
+        FanCurve::get_from_sysfs(self.get_fan_ctrl_path()?)
+    }
+
+    /// Set the fan curve. The number of `points` must match the number of points supported by the ASIC.
+    pub fn set_fan_curve(&self, points: &[(i32, u8)]) -> io::Result<()> {
+        let path = self.get_fan_ctrl_path()?.join(FAN_CURVE);
+        let cur = FanCurve::parse(&std::fs::read_to_string(&path)?);
+
+        for cmd in cur.build_commands(points)? {
+            std::fs::write(&path, cmd)?;
+        }
+
+        Ok(())
+    }
+
+    /// Enable/Disable zero RPM (fan stop) mode. Linux Kernel 6.13 or later is required.
+    pub fn set_zero_rpm(&self, enabled: bool) -> io::Result<()> {
+        let path = self.get_fan_ctrl_path()?.join(FAN_ZERO_RPM_ENABLE);
+
+        std::fs::write(&path, if enabled { "1" } else { "0" })?;
+        std::fs::write(&path, COMMIT)
+    }
+
+    fn get_fan_ctrl_path(&self) -> io::Result<PathBuf> {
+        let sysfs_path = self.get_sysfs_path()
+            .map_err(|_| io::Error::from(io::ErrorKind::NotFound))?;
+
+        Ok(sysfs_path.join(FAN_CTRL_DIR))
+    }
+}
+
+impl FanCurve {
+    pub fn get_from_sysfs<P: Into<PathBuf>>(fan_ctrl_path: P) -> io::Result<Self> {
+        let s = std::fs::read_to_string(fan_ctrl_path.into().join(FAN_CURVE))?;
+
+        Ok(Self::parse(&s))
+    }
+
+    /*
+        OD_FAN_CURVE:
+        0: 0C 0%
+        ...
+        OD_RANGE:
+        FAN_CURVE(hotspot temp): 25C 100C
+        FAN_CURVE(fan speed): 20% 100%
+    */
+    pub fn parse(s: &str) -> Self {
+        let mut points = Vec::new();
+        let mut temp_range = None;
+        let mut speed_range = None;
+
+        for line in s.lines() {
+            let Some((name, values)) = line.split_once(':') else { continue };
+            let mut split = values.split_whitespace();
+            let [a, b] = [split.next(), split.next()];
+
+            if name.trim().parse::<u32>().is_ok() {
+                let temp = a.and_then(|v| v.strip_suffix('C')?.parse().ok());
+                let speed = b.and_then(|v| v.strip_suffix('%')?.parse().ok());
+
+                if let (Some(temp), Some(speed)) = (temp, speed) {
+                    points.push((temp, speed));
+                }
+            } else if name.contains("temp") {
+                let [min, max] = [a, b].map(|v| v.and_then(|v| v.strip_suffix('C')?.parse().ok()));
+                temp_range = min.zip(max).map(|(min, max)| [min, max]);
+            } else if name.contains("speed") {
+                let [min, max] = [a, b].map(|v| v.and_then(|v| v.strip_suffix('%')?.parse().ok()));
+                speed_range = min.zip(max).map(|(min, max)| [min, max]);
+            }
+        }
+
+        Self { points, temp_range, speed_range }
+    }
+
+    /// Validate `points` and build the commands to write to `fan_curve` (`"{index} {temp} {speed}"`, then `"c"`)
+    pub fn build_commands(&self, points: &[(i32, u8)]) -> io::Result<Vec<String>> {
+        let invalid = |msg: &str| io::Error::new(io::ErrorKind::InvalidInput, msg);
+
+        if points.len() != self.points.len() {
+            return Err(invalid("The number of points does not match"));
+        }
+
+        for (temp, speed) in points {
+            if self.temp_range.is_some_and(|[min, max]| !(min..=max).contains(temp)) {
+                return Err(invalid("Temperature out of range"));
+            }
+
+            if self.speed_range.is_some_and(|[min, max]| !(min..=max).contains(speed)) {
+                return Err(invalid("Fan speed out of range"));
+            }
+        }
+
+        let mut cmds: Vec<String> = points
+            .iter()
+            .enumerate()
+            .map(|(i, (temp, speed))| format!("{i} {temp} {speed}"))
+            .collect();
+
+        cmds.push(COMMIT.to_string());
+
+        Ok(cmds)
+    }
+}
+
+#[test]
+fn test_fan_curve_commands() {
+    let s = "OD_FAN_CURVE:\n0: 0C 0%\n1: 0C 0%\n2: 0C 0%\n3: 0C 0%\n4: 0C 0%\n\
+        OD_RANGE:\nFAN_CURVE(hotspot temp): 25C 100C\nFAN_CURVE(fan speed): 20% 100%\n";
+    let cur = FanCurve::parse(s);
+
+    assert_eq!(cur.points.len(), 5);
+    assert_eq!(cur.temp_range, Some([25, 100]));
+    assert_eq!(cur.speed_range, Some([20, 100]));
+
+    let points = [(30, 20), (50, 35), (70, 50), (85, 75), (100, 100)];
+
+    assert_eq!(
+        cur.build_commands(&points).unwrap(),
+        ["0 30 20", "1 50 35", "2 70 50", "3 85 75", "4 100 100", "c"],
+    );
+    assert!(cur.build_commands(&points[..4]).is_err());
+    assert!(cur.build_commands(&[(10, 20), (50, 35), (70, 50), (85, 75), (100, 100)]).is_err());
+}
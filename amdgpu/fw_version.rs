@@ -24,7 +24,7 @@ impl DeviceHandle {
             let mut version: MaybeUninit<u32> = MaybeUninit::zeroed();
             let mut feature: MaybeUninit<u32> = MaybeUninit::zeroed();
 
-            let r = bindings::amdgpu_query_firmware_version(
+            let r = ffi::amdgpu_query_firmware_version(
                 self.0,
                 fw_type as u32,
                 ip_instance,
@@ -46,6 +46,39 @@ impl DeviceHandle {
             Ok(fw_ver)
         }
     }
+
+    /// SMU (SMC) firmware version,
+    /// from [FW_TYPE::SMC] or `fw_version/smc_fw_version` in sysfs if the query fails.  
+    /// `program` (bits 24-31), `major` (16-23), `minor` (8-15), `patch` (0-7).
+    pub fn get_smu_version(&self) -> Option<u32> {
+        let ver = self.query_firmware_version(FW_TYPE::SMC, 0, 0)
+            .ok()
+            .map(|fw| fw.version)
+            .filter(|ver| *ver != 0);
+
+        #[cfg(feature = "std")]
+        let ver = ver.or_else(|| {
+            let sysfs_path = self.get_sysfs_path().ok()?;
+            let s = std::fs::read_to_string(sysfs_path.join("fw_version/smc_fw_version")).ok()?;
+
+            u32::from_str_radix(s.trim().trim_start_matches("0x"), 16).ok().filter(|ver| *ver != 0)
+        });
+
+        ver
+    }
+
+    /// [DeviceHandle::get_smu_version] as `major.minor.patch` (e.g. "80.104.0")
+    #[cfg(feature = "std")]
+    pub fn get_smu_version_string(&self) -> Option<String> {
+        self.get_smu_version().map(smu_version_to_string)
+    }
+}
+
+#[cfg(feature = "std")]
+fn smu_version_to_string(ver: u32) -> String {
+    let [_program, major, minor, patch] = ver.to_be_bytes();
+
+    format!("{major}.{minor}.{patch}")
 }
 
 pub use crate::bindings::{
@@ -107,3 +140,10 @@ impl fmt::Display for FW_TYPE {
         write!(f, "{:?}", self)
     }
 }
+
+#[cfg(feature = "std")]
+#[test]
+fn test_smu_version_to_string() {
+    assert_eq!(smu_version_to_string(0x0050_6800), "80.104.0");
+    assert_eq!(smu_version_to_string(0x0504_2A00), "4.42.0");
+}
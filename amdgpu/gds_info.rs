@@ -0,0 +1,88 @@
+use crate::AMDGPU::{DeviceHandle, GPU_INFO};
+use crate::bindings::drm_amdgpu_info_gds;
+
+/// GDS (Global Data Share), GWS (Global Wave Sync) and OA (Ordered Append) resources of a partition
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GdsPartition {
+    /// Byte
+    pub gds_size: u32,
+    /// Number of GWS entries (barriers)
+    pub gws: u32,
+    /// Number of OA counters
+    pub oa: u32,
+}
+
+/// Typed [drm_amdgpu_info_gds].  
+/// The sizes are zero if the kernel does not expose GDS/GWS/OA to userspace (e.g. GFX11).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GdsInfo {
+    /// Byte
+    pub gds_total_size: u32,
+    pub gfx: GdsPartition,
+    pub compute: GdsPartition,
+    /// Number of shader engines, used to split the resources per SE
+    pub num_se: u32,
+}
+
+impl GdsInfo {
+    pub fn new(gds: &drm_amdgpu_info_gds, num_se: u32) -> Self {
+        Self {
+            gds_total_size: gds.gds_total_size,
+            gfx: GdsPartition {
+                gds_size: gds.gds_gfx_partition_size,
+                gws: gds.gws_per_gfx_partition,
+                oa: gds.oa_per_gfx_partition,
+            },
+            compute: GdsPartition {
+                gds_size: gds.compute_partition_size,
+                gws: gds.gws_per_compute_partition,
+                oa: gds.oa_per_compute_partition,
+            },
+            num_se,
+        }
+    }
+
+    pub fn is_available(&self) -> bool {
+        self.gds_total_size != 0
+    }
+
+    /// Compute partition resources evenly divided among shader engines
+    pub fn compute_per_se(&self) -> GdsPartition {
+        let div = |v: u32| v.checked_div(self.num_se).unwrap_or(0);
+
+        GdsPartition {
+            gds_size: div(self.compute.gds_size),
+            gws: div(self.compute.gws),
+            oa: div(self.compute.oa),
+        }
+    }
+}
+
+impl DeviceHandle {
+    /// Get [GdsInfo] with the number of shader engines from [DeviceHandle::device_info]
+    pub fn get_gds_info(&self) -> Result<GdsInfo, i32> {
+        let num_se = self.device_info()?.max_se();
+        let gds = self.gds_info()?;
+
+        Ok(GdsInfo::new(&gds, num_se))
+    }
+}
+
+#[test]
+fn test_gds_info() {
+    let mut gds: drm_amdgpu_info_gds = unsafe { core::mem::zeroed() };
+
+    gds.gds_total_size = 0x10000;
+    gds.gds_gfx_partition_size = 0x10000;
+    gds.compute_partition_size = 0x10000;
+    gds.gws_per_gfx_partition = 64;
+    gds.gws_per_compute_partition = 64;
+    gds.oa_per_gfx_partition = 16;
+    gds.oa_per_compute_partition = 16;
+
+    let info = GdsInfo::new(&gds, 4);
+
+    assert!(info.is_available());
+    assert_eq!(info.compute_per_se(), GdsPartition { gds_size: 0x4000, gws: 16, oa: 4 });
+    assert_eq!(GdsInfo::new(&gds, 0).compute_per_se(), GdsPartition::default());
+}
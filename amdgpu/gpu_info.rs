@@ -38,10 +38,25 @@ pub trait GPU_INFO {
         AMDGPU::VRAM_TYPE::from(self.vram_type())
     }
 
+    /// e.g. "GDDR6", "HBM3E"
+    fn vram_type_str(&self) -> &'static str {
+        self.get_vram_type().as_str()
+    }
+
+    fn ids_flags_parsed(&self) -> AMDGPU::DeviceIdsFlags {
+        AMDGPU::DeviceIdsFlags::new(self.ids_flags())
+    }
+
     fn is_apu(&self) -> bool {
-        use crate::bindings::AMDGPU_IDS_FLAGS_FUSION;
+        self.ids_flags_parsed().is_apu()
+    }
 
-        (self.ids_flags() & AMDGPU_IDS_FLAGS_FUSION as u64) != 0
+    /// Check if the device is running as an SR-IOV virtual function (VF).  
+    /// Under SR-IOV, the host (PF) owns power management, so sensors, `gpu_metrics`,
+    /// overclocking (`pp_od_clk_voltage`) and DPM control are usually unavailable on a VF.  
+    /// Note: The virtualization mode bits are only reported by Linux Kernel 6.10 or later.
+    fn is_virtual_function(&self) -> bool {
+        self.ids_flags_parsed().is_virtual_function()
     }
 
     fn peak_memory_bw(&self) -> u64 {
@@ -232,6 +247,48 @@ impl drm_amdgpu_info_device {
         self.get_actual_num_tcc_blocks() * self.get_asic_name().l3_cache_size_mb_per_channel()
     }
 
+    /// Number of active CUs of each entry of `cu_bitmap`, in the same layout as `cu_bitmap`.
+    /// Entries beyond `num_shader_engines`/`num_shader_arrays_per_engine` are 0.  
+    /// The kernel stores SE `se`, SA `sa` at `cu_bitmap[se % 4][sa + (se / 4) * 2]`,
+    /// so it is `[se][sa]` for up to 4 SEs.
+    pub fn cu_per_se(&self) -> [[u32; 4]; 4] {
+        let mut counts = [[0u32; 4]; 4];
+
+        for se in 0..self.num_shader_engines.min(8) as usize {
+            for sa in 0..self.num_shader_arrays_per_engine.min(4) as usize {
+                let [i, j] = [se % 4, sa + (se / 4) * 2];
+                let Some(bitmap) = self.cu_bitmap.get(i).and_then(|v| v.get(j)) else { continue };
+
+                counts[i][j] = bitmap.count_ones();
+            }
+        }
+
+        counts
+    }
+
+    /// Number of active CUs counted from `cu_bitmap`
+    pub fn active_cu_count(&self) -> u32 {
+        self.cu_per_se().iter().flatten().sum()
+    }
+
+    /// Max PCIe gen reported by the kernel (`pcie_gen`), `None` on older kernels.
+    pub fn get_pcie_gen(&self) -> Option<u8> {
+        u8::try_from(self.pcie_gen).ok().filter(|gen| *gen != 0)
+    }
+
+    /// Max number of PCIe lanes reported by the kernel (`pcie_num_lanes`), `None` on older kernels.
+    pub fn get_pcie_num_lanes(&self) -> Option<u8> {
+        u8::try_from(self.pcie_num_lanes).ok().filter(|width| *width != 0)
+    }
+
+    /// Max PCIe link reported by the kernel, without reading sysfs.  
+    /// The kernel derives it from the link capabilities of both the GPU and the platform,
+    /// so comparing it with [PCI::BUS_INFO::get_max_gpu_link] shows whether the card is
+    /// in a slower slot.
+    pub fn get_pcie_link(&self) -> Option<PCI::LINK> {
+        Some(PCI::LINK { gen: self.get_pcie_gen()?, width: self.get_pcie_num_lanes()? })
+    }
+
     /// ref: drivers/gpu/drm/amd/amd/amdkfd/kfd_device.c
     pub fn get_gfx_target_version(&self) -> Option<GfxTargetVersion> {
         use AMDGPU::ASIC_NAME;
@@ -267,7 +324,7 @@ impl drm_amdgpu_info_device {
             ASIC_NAME::CHIP_NAVI10 => (10, 1, 0),
             ASIC_NAME::CHIP_NAVI12 => (10, 1, 1),
             ASIC_NAME::CHIP_NAVI14 => (10, 1, 2),
-            // TODO: ASIC_NAME::CYAN_SKILLFISH
+            ASIC_NAME::CHIP_GFX1013 => (10, 1, 3),
             ASIC_NAME::CHIP_NAVI21 => (10, 3, 0),
             ASIC_NAME::CHIP_NAVI22 => (10, 3, 1),
             ASIC_NAME::CHIP_VANGOGH => (10, 3, 3),
@@ -282,6 +339,8 @@ impl drm_amdgpu_info_device {
             ASIC_NAME::CHIP_GFX1103_R2 => (11, 0, 3),
             ASIC_NAME::CHIP_GFX1150 => (11, 5, 0),
             ASIC_NAME::CHIP_GFX1151 => (11, 5, 1),
+            ASIC_NAME::CHIP_GFX1152 => (11, 5, 2),
+            ASIC_NAME::CHIP_GFX1153 => (11, 5, 3),
             _ => return None,
         };
 
@@ -289,6 +348,56 @@ impl drm_amdgpu_info_device {
     }
 }
 
+#[test]
+fn test_active_cu_count() {
+    let mut dev_info: drm_amdgpu_info_device = unsafe { core::mem::zeroed() };
+
+    /* Navi21, 4 SE, 2 SA/SE, 10 CU/SA (1 WGP harvested on SE0/SA0) */
+    dev_info.num_shader_engines = 4;
+    dev_info.num_shader_arrays_per_engine = 2;
+    dev_info.cu_bitmap = [[0x3FF; 4]; 4];
+    dev_info.cu_bitmap[0][0] = 0xFF;
+
+    assert_eq!(dev_info.active_cu_count(), 78);
+    assert_eq!(dev_info.cu_per_se()[0], [8, 10, 0, 0]);
+
+    /* Navi31, 6 SE */
+    dev_info.num_shader_engines = 6;
+    dev_info.cu_bitmap = [[0xFF; 4]; 4];
+
+    assert_eq!(dev_info.active_cu_count(), 96);
+    assert_eq!(dev_info.cu_per_se()[1], [8, 8, 8, 8]);
+    assert_eq!(dev_info.cu_per_se()[2], [8, 8, 0, 0]);
+}
+
+#[test]
+fn test_pcie_link() {
+    let mut dev_info: drm_amdgpu_info_device = unsafe { core::mem::zeroed() };
+
+    assert_eq!(dev_info.get_pcie_link(), None);
+
+    dev_info.pcie_gen = 4;
+    dev_info.pcie_num_lanes = 16;
+    assert_eq!(dev_info.get_pcie_link(), Some(PCI::LINK { gen: 4, width: 16 }));
+}
+
+#[test]
+fn test_is_virtual_function() {
+    use super::ids_flags::{AMDGPU_IDS_FLAGS_MODE_SHIFT, AMDGPU_IDS_FLAGS_MODE_VF};
+
+    let mut dev_info: drm_amdgpu_info_device = unsafe { core::mem::zeroed() };
+
+    assert!(!dev_info.is_virtual_function());
+
+    dev_info.ids_flags = (AMDGPU_IDS_FLAGS_MODE_VF << AMDGPU_IDS_FLAGS_MODE_SHIFT)
+        | crate::bindings::AMDGPU_IDS_FLAGS_FUSION as u64;
+    assert!(dev_info.is_virtual_function());
+
+    /* passthrough */
+    dev_info.ids_flags = 0x2 << AMDGPU_IDS_FLAGS_MODE_SHIFT;
+    assert!(!dev_info.is_virtual_function());
+}
+
 #[cfg(feature = "std")]
 pub fn find_device_name(device_id: u32, revision_id: u32) -> Option<String> {
     use bindings::AMDGPU_IDS;
@@ -0,0 +1,98 @@
+use crate::AMDGPU::{DeviceHandle, amdgpu_gpu_info, drm_amdgpu_info_device};
+
+/// A field reported differently by [DeviceHandle::device_info] (`AMDGPU_INFO_DEV_INFO`)
+/// and [DeviceHandle::query_gpu_info] (`amdgpu_query_gpu_info` of libdrm_amdgpu)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mismatch {
+    /// Field name of `drm_amdgpu_info_device`
+    pub field: &'static str,
+    pub device_info: u64,
+    pub gpu_info: u64,
+}
+
+impl Mismatch {
+    /// Compare the overlapping fields
+    pub fn compare(dev: &drm_amdgpu_info_device, gpu: &amdgpu_gpu_info) -> Vec<Self> {
+        let fields: [(&'static str, u64, u64); 20] = [
+            ("device_id", dev.device_id as u64, gpu.asic_id as u64),
+            ("chip_rev", dev.chip_rev as u64, gpu.chip_rev as u64),
+            ("external_rev", dev.external_rev as u64, gpu.chip_external_rev as u64),
+            ("pci_rev", dev.pci_rev as u64, gpu.pci_rev_id as u64),
+            ("family", dev.family as u64, gpu.family_id as u64),
+            ("num_shader_engines", dev.num_shader_engines as u64, gpu.num_shader_engines as u64),
+            (
+                "num_shader_arrays_per_engine",
+                dev.num_shader_arrays_per_engine as u64,
+                gpu.num_shader_arrays_per_engine as u64,
+            ),
+            ("gpu_counter_freq", dev.gpu_counter_freq as u64, gpu.gpu_counter_freq as u64),
+            ("max_engine_clock", dev.max_engine_clock, gpu.max_engine_clk),
+            ("max_memory_clock", dev.max_memory_clock, gpu.max_memory_clk),
+            ("cu_active_number", dev.cu_active_number as u64, gpu.cu_active_number as u64),
+            ("cu_ao_mask", dev.cu_ao_mask as u64, gpu.cu_ao_mask as u64),
+            ("enabled_rb_pipes_mask", dev.enabled_rb_pipes_mask as u64, gpu.enabled_rb_pipes_mask as u64),
+            ("num_rb_pipes", dev.num_rb_pipes as u64, gpu.rb_pipes as u64),
+            ("num_hw_gfx_contexts", dev.num_hw_gfx_contexts as u64, gpu.num_hw_gfx_contexts as u64),
+            ("ids_flags", dev.ids_flags, gpu.ids_flags),
+            ("ce_ram_size", dev.ce_ram_size as u64, gpu.ce_ram_size as u64),
+            ("vram_type", dev.vram_type as u64, gpu.vram_type as u64),
+            ("vram_bit_width", dev.vram_bit_width as u64, gpu.vram_bit_width as u64),
+            ("vce_harvest_config", dev.vce_harvest_config as u64, gpu.vce_harvest_config as u64),
+        ];
+
+        let mut mismatches: Vec<Self> = fields
+            .into_iter()
+            .filter(|(_, d, g)| d != g)
+            .map(|(field, device_info, gpu_info)| Self { field, device_info, gpu_info })
+            .collect();
+
+        // the number of active CUs in `cu_bitmap`
+        let count = |bitmap: &[[u32; 4]; 4]| -> u64 {
+            bitmap.iter().flatten().map(|v| v.count_ones() as u64).sum()
+        };
+
+        if dev.cu_bitmap != gpu.cu_bitmap {
+            mismatches.push(Self {
+                field: "cu_bitmap",
+                device_info: count(&dev.cu_bitmap),
+                gpu_info: count(&gpu.cu_bitmap),
+            });
+        }
+
+        mismatches
+    }
+}
+
+impl DeviceHandle {
+    /// Compare the overlapping fields of [DeviceHandle::device_info] and [DeviceHandle::query_gpu_info],
+    /// for diagnosing driver/library bugs. An empty `Vec` means no discrepancy.
+    /// For `cu_bitmap`, the values of [Mismatch] are the number of set bits.
+    /// `max_engine_clock`/`max_memory_clock` are in KHz on both sides.
+    pub fn gpu_info_consistency_check(&self) -> Result<Vec<Mismatch>, i32> {
+        let dev = self.device_info()?;
+        let gpu = self.query_gpu_info()?;
+
+        Ok(Mismatch::compare(&dev, &gpu))
+    }
+}
+
+#[test]
+fn test_gpu_info_mismatch() {
+    let mut dev: drm_amdgpu_info_device = unsafe { core::mem::zeroed() };
+    let mut gpu: amdgpu_gpu_info = unsafe { core::mem::zeroed() };
+
+    dev.cu_active_number = 60;
+    gpu.cu_active_number = 60;
+    assert!(Mismatch::compare(&dev, &gpu).is_empty());
+
+    dev.max_engine_clock = 2_500_000;
+    gpu.cu_bitmap[0][0] = 0b11;
+
+    assert_eq!(
+        Mismatch::compare(&dev, &gpu),
+        [
+            Mismatch { field: "max_engine_clock", device_info: 2_500_000, gpu_info: 0 },
+            Mismatch { field: "cu_bitmap", device_info: 0, gpu_info: 2 },
+        ],
+    );
+}
@@ -0,0 +1,248 @@
+use crate::AMDGPU::DeviceHandle;
+use crate::PCI;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// GPU usage of a process, aggregated over the DRM clients (opened files) of the process.
+/// ref: <https://docs.kernel.org/gpu/drm-usage-stats.html>
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GpuProcess {
+    pub pid: u32,
+    /// Byte
+    pub vram_bytes: u64,
+    /// Byte
+    pub gtt_bytes: u64,
+    /// Cumulative GFX engine busy time, ns
+    pub gfx_ns: u64,
+    /// Cumulative compute engine busy time, ns
+    pub compute_ns: u64,
+    /// Cumulative DMA (SDMA) engine busy time, ns
+    pub dma_ns: u64,
+    /// Cumulative decode engine busy time, ns
+    pub dec_ns: u64,
+    /// Cumulative encode engine busy time, ns
+    pub enc_ns: u64,
+}
+
+/// Usage of a DRM client parsed from `/proc/<pid>/fdinfo/<fd>`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DrmClientUsage {
+    /// `drm-client-id`, shared by the duplicated file descriptors
+    pub client_id: u64,
+    /// `drm-pdev`, e.g. `"0000:03:00.0"`
+    pub pdev: String,
+    /// `drm-memory-vram` (older kernels) or `drm-resident-vram`, Byte
+    pub vram_bytes: u64,
+    /// `drm-memory-gtt` (older kernels) or `drm-resident-gtt`, Byte
+    pub gtt_bytes: u64,
+    /// `drm-engine-gfx`, ns
+    pub gfx_ns: u64,
+    /// `drm-engine-compute`, ns
+    pub compute_ns: u64,
+    /// `drm-engine-dma` (SDMA), ns
+    pub dma_ns: u64,
+    /// `drm-engine-dec` (UVD/VCN decode), ns
+    pub dec_ns: u64,
+    /// `drm-engine-enc` and `drm-engine-enc_1` (VCE/UVD/VCN encode), ns
+    pub enc_ns: u64,
+}
+
+impl DrmClientUsage {
+    /// Returns `None` if the fdinfo is not of an amdgpu DRM client
+    pub fn parse(s: &str) -> Option<Self> {
+        let mut is_amdgpu = false;
+        let mut client_id: Option<u64> = None;
+        let mut pdev = String::new();
+        let [mut vram, mut gtt, mut resident_vram, mut resident_gtt] = [None; 4];
+        let [mut gfx_ns, mut compute_ns, mut dma_ns, mut dec_ns, mut enc_ns] = [0u64; 5];
+
+        for line in s.lines() {
+            let Some((key, val)) = line.split_once(':') else { continue };
+            let val = val.trim();
+
+            match key {
+                "drm-driver" => is_amdgpu = val == "amdgpu",
+                "drm-client-id" => client_id = val.parse().ok(),
+                "drm-pdev" => pdev = val.to_string(),
+                "drm-memory-vram" => vram = Self::parse_size(val),
+                "drm-memory-gtt" => gtt = Self::parse_size(val),
+                "drm-resident-vram" => resident_vram = Self::parse_size(val),
+                "drm-resident-gtt" => resident_gtt = Self::parse_size(val),
+                "drm-engine-gfx" => gfx_ns = Self::parse_ns(val).unwrap_or(0),
+                "drm-engine-compute" => compute_ns = Self::parse_ns(val).unwrap_or(0),
+                "drm-engine-dma" => dma_ns = Self::parse_ns(val).unwrap_or(0),
+                "drm-engine-dec" => dec_ns = Self::parse_ns(val).unwrap_or(0),
+                "drm-engine-enc" |
+                "drm-engine-enc_1" => enc_ns += Self::parse_ns(val).unwrap_or(0),
+                _ => {},
+            }
+        }
+
+        if !is_amdgpu { return None }
+
+        Some(Self {
+            client_id: client_id?,
+            pdev,
+            vram_bytes: vram.or(resident_vram).unwrap_or(0),
+            gtt_bytes: gtt.or(resident_gtt).unwrap_or(0),
+            gfx_ns,
+            compute_ns,
+            dma_ns,
+            dec_ns,
+            enc_ns,
+        })
+    }
+
+    /// "1024 KiB", "4 MiB", "512" (Byte)
+    fn parse_size(val: &str) -> Option<u64> {
+        let mut split = val.split_whitespace();
+        let num = split.next()?.parse::<u64>().ok()?;
+        let shift = match split.next() {
+            None => 0,
+            Some("KiB") => 10,
+            Some("MiB") => 20,
+            Some("GiB") => 30,
+            Some(_) => return None,
+        };
+
+        Some(num << shift)
+    }
+
+    /// "12345 ns"
+    fn parse_ns(val: &str) -> Option<u64> {
+        val.strip_suffix("ns")?.trim().parse().ok()
+    }
+}
+
+/// Scan `/proc/<pid>/fdinfo/*` for the amdgpu DRM clients of the device at `bus`,
+/// and aggregate the usage per process (sorted by PID).
+/// Processes whose fd/fdinfo cannot be read (other users' processes without root privileges,
+/// processes exited during the scan) are skipped.
+/// Requires Linux 5.19 or later for the `drm-*` keys.
+pub fn list_gpu_processes(bus: &PCI::BUS_INFO) -> Vec<GpuProcess> {
+    aggregate_by_pid(scan_drm_clients(&bus.to_string()))
+}
+
+fn aggregate_by_pid(clients: Vec<(u32, DrmClientUsage)>) -> Vec<GpuProcess> {
+    let mut procs: HashMap<u32, GpuProcess> = HashMap::new();
+
+    for (pid, c) in clients {
+        let p = procs.entry(pid).or_insert(GpuProcess { pid, ..Default::default() });
+
+        p.vram_bytes += c.vram_bytes;
+        p.gtt_bytes += c.gtt_bytes;
+        p.gfx_ns += c.gfx_ns;
+        p.compute_ns += c.compute_ns;
+        p.dma_ns += c.dma_ns;
+        p.dec_ns += c.dec_ns;
+        p.enc_ns += c.enc_ns;
+    }
+
+    let mut procs: Vec<GpuProcess> = procs.into_values().collect();
+
+    procs.sort_by_key(|p| p.pid);
+
+    procs
+}
+
+/// (PID, client) of all readable processes, each client once per process
+pub(crate) fn scan_drm_clients(pdev: &str) -> Vec<(u32, DrmClientUsage)> {
+    let Ok(proc_dir) = std::fs::read_dir("/proc") else { return Vec::new() };
+
+    proc_dir
+        .flatten()
+        .filter_map(|entry| {
+            let pid = entry.file_name().to_str()?.parse::<u32>().ok()?;
+
+            Some(get_drm_clients(&entry.path(), pdev).into_iter().map(move |c| (pid, c)))
+        })
+        .flatten()
+        .collect()
+}
+
+fn get_drm_clients(proc_path: &Path, pdev: &str) -> Vec<DrmClientUsage> {
+    let mut clients: Vec<DrmClientUsage> = Vec::new();
+    let Ok(fds) = std::fs::read_dir(proc_path.join("fd")) else { return clients };
+
+    for fd in fds.flatten() {
+        let Ok(link) = std::fs::read_link(fd.path()) else { continue };
+
+        if !link.starts_with("/dev/dri/") { continue }
+
+        let Ok(s) = std::fs::read_to_string(proc_path.join("fdinfo").join(fd.file_name())) else {
+            continue;
+        };
+        let Some(usage) = DrmClientUsage::parse(&s) else { continue };
+
+        if usage.pdev != pdev || clients.iter().any(|c| c.client_id == usage.client_id) {
+            continue;
+        }
+
+        clients.push(usage);
+    }
+
+    clients
+}
+
+impl DeviceHandle {
+    /// [list_gpu_processes] for the device
+    pub fn list_gpu_processes(&self) -> Result<Vec<GpuProcess>, i32> {
+        Ok(list_gpu_processes(&self.get_pci_bus_info()?))
+    }
+}
+
+#[test]
+fn test_drm_client_usage_parse() {
+    let s = "pos:\t0\n\
+        flags:\t02100002\n\
+        drm-driver:\tamdgpu\n\
+        drm-client-id:\t42\n\
+        drm-pdev:\t0000:03:00.0\n\
+        pasid:\t32771\n\
+        drm-memory-vram:\t2048 KiB\n\
+        drm-memory-gtt: \t4 MiB\n\
+        drm-engine-gfx:\t123456 ns\n\
+        drm-engine-enc:\t100 ns\n\
+        drm-engine-enc_1:\t20 ns\n";
+    let usage = DrmClientUsage::parse(s).unwrap();
+
+    assert_eq!(usage.client_id, 42);
+    assert_eq!(usage.pdev, "0000:03:00.0");
+    assert_eq!(usage.vram_bytes, 2 << 20);
+    assert_eq!(usage.gtt_bytes, 4 << 20);
+    assert_eq!(usage.gfx_ns, 123456);
+    assert_eq!(usage.compute_ns, 0);
+    assert_eq!(usage.enc_ns, 120);
+
+    let new = "drm-driver:\tamdgpu\ndrm-client-id:\t7\ndrm-resident-vram:\t512\n";
+
+    assert_eq!(DrmClientUsage::parse(new).unwrap().vram_bytes, 512);
+    assert!(DrmClientUsage::parse("drm-driver:\ti915\ndrm-client-id:\t1\n").is_none());
+}
+
+#[test]
+fn test_aggregate_by_pid() {
+    let client = |client_id, gfx_ns, dec_ns| DrmClientUsage {
+        client_id,
+        pdev: "0000:03:00.0".to_string(),
+        vram_bytes: 1 << 20,
+        gtt_bytes: 0,
+        gfx_ns,
+        compute_ns: 0,
+        dma_ns: 0,
+        dec_ns,
+        enc_ns: 0,
+    };
+    let procs = aggregate_by_pid(vec![
+        (200, client(3, 0, 500)),
+        (100, client(1, 1000, 0)),
+        (200, client(4, 2000, 300)),
+    ]);
+
+    assert_eq!(procs.len(), 2);
+    assert_eq!(procs[0], GpuProcess { pid: 100, vram_bytes: 1 << 20, gfx_ns: 1000, ..Default::default() });
+    assert_eq!(
+        procs[1],
+        GpuProcess { pid: 200, vram_bytes: 2 << 20, gfx_ns: 2000, dec_ns: 800, ..Default::default() },
+    );
+}
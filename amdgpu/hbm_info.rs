@@ -0,0 +1,88 @@
+use crate::AMDGPU::{DeviceHandle, GPU_INFO};
+use std::path::PathBuf;
+
+impl DeviceHandle {
+    /// Returns `None` if VRAM is not HBM
+    pub fn get_hbm_info(&self) -> Option<HbmInfo> {
+        let vram_type = self.device_info().ok()?.get_vram_type();
+
+        if !vram_type.is_hbm() { return None }
+
+        let sysfs_path = self.get_sysfs_path().ok()?;
+        let hwmon_path = self.get_hwmon_path()?;
+
+        Some(HbmInfo::from_sysfs_path(sysfs_path, hwmon_path))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HbmInfo {
+    /// `mem_info_vram_vendor`
+    pub vendor: Option<String>,
+    /// Celsius, per memory stack
+    pub stack_temps: Vec<i32>,
+}
+
+impl HbmInfo {
+    pub fn from_sysfs_path<P: Into<PathBuf>, Q: Into<PathBuf>>(sysfs_path: P, hwmon_path: Q) -> Self {
+        let vendor = DeviceHandle::get_vram_vendor_from_sysfs(sysfs_path);
+        let stack_temps = Self::get_stack_temps_from_hwmon_path(hwmon_path);
+
+        Self { vendor, stack_temps }
+    }
+
+    /// Read all `temp#_input` whose `temp#_label` is labeled as memory (`mem`, `hbm`),
+    /// sorted by channel number.
+    pub fn get_stack_temps_from_hwmon_path<P: Into<PathBuf>>(hwmon_path: P) -> Vec<i32> {
+        let path = hwmon_path.into();
+        let Ok(dir) = std::fs::read_dir(&path) else { return Vec::new() };
+
+        let sensors: Vec<(u32, String, String)> = dir.filter_map(|entry| {
+            let name = entry.ok()?.file_name().into_string().ok()?;
+            let ch = name.strip_prefix("temp")?.strip_suffix("_label")?.parse::<u32>().ok()?;
+            let label = std::fs::read_to_string(path.join(&name)).ok()?;
+            let input = std::fs::read_to_string(path.join(format!("temp{ch}_input"))).ok()?;
+
+            Some((ch, label, input))
+        }).collect();
+
+        Self::parse_stack_temps(
+            sensors.iter().map(|(ch, label, input)| (*ch, label.as_str(), input.as_str()))
+        )
+    }
+
+    /// Parse (channel, `temp#_label`, `temp#_input`) of hwmon,
+    /// and return the memory temperatures (Celsius) sorted by channel number.
+    pub fn parse_stack_temps<'a, I>(sensors: I) -> Vec<i32>
+    where
+        I: IntoIterator<Item = (u32, &'a str, &'a str)>,
+    {
+        let mut temps: Vec<(u32, i32)> = sensors.into_iter().filter_map(|(ch, label, input)| {
+            let label = label.to_lowercase();
+
+            if !(label.starts_with("mem") || label.starts_with("hbm")) { return None }
+
+            let temp = input.trim_end().parse::<i32>().ok()?;
+
+            Some((ch, temp.saturating_div(1_000)))
+        }).collect();
+
+        temps.sort_by_key(|(ch, _)| *ch);
+
+        temps.into_iter().map(|(_, temp)| temp).collect()
+    }
+}
+
+#[test]
+fn test_hbm_stack_temps() {
+    let temps = HbmInfo::parse_stack_temps([
+        (4, "hbm1\n", "52000\n"),
+        (1, "edge\n", "40000\n"),
+        (2, "junction\n", "45000\n"),
+        (5, "HBM2\n", "51000\n"),
+        (3, "mem\n", "50000\n"),
+        (6, "mem\n", "N/A\n"),
+    ]);
+
+    assert_eq!(temps, vec![50, 52, 51]);
+}
@@ -30,7 +30,7 @@ impl DeviceHandle {
         unsafe {
             let mut hw_ip_count: MaybeUninit<u32> = MaybeUninit::zeroed();
 
-            let r = bindings::amdgpu_query_hw_ip_count(
+            let r = ffi::amdgpu_query_hw_ip_count(
                 self.0,
                 type_ as ::core::ffi::c_uint,
                 hw_ip_count.as_mut_ptr(),
@@ -53,7 +53,7 @@ impl DeviceHandle {
         unsafe {
             let mut hw_ip_info: MaybeUninit<drm_amdgpu_info_hw_ip> = MaybeUninit::zeroed();
 
-            let r = bindings::amdgpu_query_hw_ip_info(
+            let r = ffi::amdgpu_query_hw_ip_info(
                 self.0,
                 type_ as ::core::ffi::c_uint,
                 ip_instance as ::core::ffi::c_uint,
@@ -67,17 +67,82 @@ impl DeviceHandle {
             Ok(hw_ip_info)
         }
     }
+
+    /// Number of the available engine instances of `type_`, the set bits of `available_rings`.  
+    /// The kernel accepts only `ip_instance == 0` (`AMDGPU_HW_IP_INSTANCE_MAX_COUNT`),
+    /// multiple engines (e.g. VCN instances of Navi31) are exposed as rings of the instance 0.
+    pub fn count_hw_ip_instances(&self, type_: HW_IP_TYPE) -> u32 {
+        self.query_hw_ip_info(type_, 0).map_or(0, |info| info.num_queues())
+    }
+
+    /// Indices of the available rings of `type_`, one per engine instance,
+    /// see [DeviceHandle::count_hw_ip_instances]
+    #[cfg(feature = "std")]
+    pub fn get_hw_ip_instance_rings(&self, type_: HW_IP_TYPE) -> Vec<u32> {
+        self.query_hw_ip_info(type_, 0).map_or(Vec::new(), |info| info.ring_indices())
+    }
+
+    /// Number of the available VCN instances.  
+    /// Each instance has one `VCN_DEC` ring, or only `VCN_ENC` rings with the unified queue (VCN 4.0 or later).
+    pub fn count_vcn_instances(&self) -> u32 {
+        let [dec, enc] = [HW_IP_TYPE::VCN_DEC, HW_IP_TYPE::VCN_ENC]
+            .map(|ip_type| self.query_hw_ip_info(ip_type, 0).map_or(0, |info| info.available_rings));
+
+        vcn_instances_from_rings(dec, enc)
+    }
+}
+
+/// Before VCN 4.0, each instance has multiple `VCN_ENC` rings
+fn vcn_instances_from_rings(dec_rings: u32, enc_rings: u32) -> u32 {
+    if dec_rings != 0 { dec_rings.count_ones() } else { enc_rings.count_ones() }
+}
+
+impl HwIpInfo {
+    /// Indices of the available rings (queues) decoded from `available_rings`
+    #[cfg(feature = "std")]
+    pub fn ring_indices(&self) -> Vec<u32> {
+        self.info.ring_indices()
+    }
+
+    /// Number of the available rings (queues)
+    pub fn num_rings(&self) -> u32 {
+        self.info.num_queues()
+    }
 }
 
 impl drm_amdgpu_info_hw_ip {
     pub fn num_queues(&self) -> u32 {
         self.available_rings.count_ones()
     }
+    /// Indices of the available rings (queues) decoded from `available_rings`
+    #[cfg(feature = "std")]
+    pub fn ring_indices(&self) -> Vec<u32> {
+        (0..u32::BITS).filter(|i| (self.available_rings >> i) & 0b1 == 1).collect()
+    }
     pub fn version(&self) -> (u32, u32) {
         (self.hw_ip_version_major, self.hw_ip_version_minor)
     }
 }
 
+#[cfg(feature = "std")]
+#[test]
+fn test_ring_indices() {
+    let mut info: drm_amdgpu_info_hw_ip = unsafe { core::mem::zeroed() };
+    info.available_rings = 0b1011_0001;
+
+    assert_eq!(info.ring_indices(), vec![0, 4, 5, 7]);
+    assert_eq!(info.num_queues(), 4);
+}
+
+#[test]
+fn test_vcn_instances_from_rings() {
+    // Navi21: 2 VCN instances, 2 encode rings per instance
+    assert_eq!(vcn_instances_from_rings(0b11, 0b1111), 2);
+    // Navi31: unified queue
+    assert_eq!(vcn_instances_from_rings(0, 0b11), 2);
+    assert_eq!(vcn_instances_from_rings(0, 0), 0);
+}
+
 use crate::bindings::{
     AMDGPU_HW_IP_COMPUTE,
     AMDGPU_HW_IP_DMA,
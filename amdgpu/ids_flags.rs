@@ -0,0 +1,126 @@
+use crate::AMDGPU::DeviceHandle;
+use crate::bindings::{
+    AMDGPU_IDS_FLAGS_FUSION,
+    AMDGPU_IDS_FLAGS_PREEMPTION,
+    AMDGPU_IDS_FLAGS_TMZ,
+    AMDGPU_IDS_FLAGS_CONFORMANT_TRUNC_COORD,
+};
+
+/* ref: include/uapi/drm/amdgpu_drm.h */
+pub(crate) const AMDGPU_IDS_FLAGS_MODE_MASK: u64 = 0x300;
+pub(crate) const AMDGPU_IDS_FLAGS_MODE_SHIFT: u64 = 0x8;
+// pub(crate) const AMDGPU_IDS_FLAGS_MODE_PF: u64 = 0x0;
+pub(crate) const AMDGPU_IDS_FLAGS_MODE_VF: u64 = 0x1;
+// pub(crate) const AMDGPU_IDS_FLAGS_MODE_PT: u64 = 0x2;
+
+/// Flags of `ids_flags` in [crate::AMDGPU::drm_amdgpu_info_device] and [crate::AMDGPU::amdgpu_gpu_info]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeviceIdsFlags(u64);
+
+impl DeviceIdsFlags {
+    pub const FUSION: Self = Self(AMDGPU_IDS_FLAGS_FUSION as u64);
+    pub const PREEMPTION: Self = Self(AMDGPU_IDS_FLAGS_PREEMPTION as u64);
+    pub const TMZ: Self = Self(AMDGPU_IDS_FLAGS_TMZ as u64);
+    pub const CONFORMANT_TRUNC_COORD: Self = Self(AMDGPU_IDS_FLAGS_CONFORMANT_TRUNC_COORD as u64);
+
+    pub fn new(val: u64) -> Self {
+        Self(val)
+    }
+
+    pub fn bits(&self) -> u64 {
+        self.0
+    }
+
+    pub fn contains(&self, other: Self) -> bool {
+        (self.0 & other.0) == other.0
+    }
+
+    pub fn is_apu(&self) -> bool {
+        self.contains(Self::FUSION)
+    }
+
+    /// Trusted Memory Zone
+    pub fn supports_tmz(&self) -> bool {
+        self.contains(Self::TMZ)
+    }
+
+    /// Mid-command buffer preemption
+    pub fn supports_preemption(&self) -> bool {
+        self.contains(Self::PREEMPTION)
+    }
+
+    pub fn has_conformant_trunc_coord(&self) -> bool {
+        self.contains(Self::CONFORMANT_TRUNC_COORD)
+    }
+
+    /// SR-IOV virtual function
+    pub fn is_virtual_function(&self) -> bool {
+        ((self.0 & AMDGPU_IDS_FLAGS_MODE_MASK) >> AMDGPU_IDS_FLAGS_MODE_SHIFT)
+            == AMDGPU_IDS_FLAGS_MODE_VF
+    }
+}
+
+/// Runtime capabilities of the device, from `ids_flags` of [crate::AMDGPU::drm_amdgpu_info_device].  
+/// The upstream kernel has no `AMDGPU_INFO_CAPABILITY` query (it only exists in the out-of-tree driver),
+/// but `ids_flags` is set at runtime, so the flags reflect the current state rather than the ASIC support.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Capabilities {
+    /// Trusted Memory Zone is enabled (`amdgpu_is_tmz`), amdgpu KMS 3.40.0 or later.  
+    /// It is disabled by default on most ASICs, and controlled by `amdgpu.tmz` module parameter.
+    pub tmz_enabled: bool,
+    /// Mid-command buffer preemption is enabled (`amdgpu_mcbp`)
+    pub preemption_enabled: bool,
+    /// amdgpu KMS 3.52.0 or later
+    pub conformant_trunc_coord: bool,
+    /// SR-IOV virtual function, amdgpu KMS 3.62.0 or later
+    pub virtual_function: bool,
+}
+
+impl From<DeviceIdsFlags> for Capabilities {
+    fn from(flags: DeviceIdsFlags) -> Self {
+        Self {
+            tmz_enabled: flags.supports_tmz(),
+            preemption_enabled: flags.supports_preemption(),
+            conformant_trunc_coord: flags.has_conformant_trunc_coord(),
+            virtual_function: flags.is_virtual_function(),
+        }
+    }
+}
+
+impl DeviceHandle {
+    pub fn query_capability(&self) -> Result<Capabilities, i32> {
+        let flags = DeviceIdsFlags::new(self.device_info()?.ids_flags);
+
+        Ok(Capabilities::from(flags))
+    }
+}
+
+impl From<u64> for DeviceIdsFlags {
+    fn from(val: u64) -> Self {
+        Self(val)
+    }
+}
+
+impl core::ops::BitOr for DeviceIdsFlags {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+#[test]
+fn test_device_ids_flags() {
+    let flags = DeviceIdsFlags::FUSION | DeviceIdsFlags::TMZ;
+
+    assert!(flags.is_apu());
+    assert!(flags.supports_tmz());
+    assert!(!flags.supports_preemption());
+    assert!(!flags.has_conformant_trunc_coord());
+    assert_eq!(flags.bits(), 0b101);
+
+    let cap = Capabilities::from(flags);
+
+    assert!(cap.tmz_enabled);
+    assert!(!cap.preemption_enabled);
+}
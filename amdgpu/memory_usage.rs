@@ -0,0 +1,144 @@
+use crate::AMDGPU::DeviceHandle;
+use crate::bindings::drm_amdgpu_memory_info;
+use core::fmt;
+
+/// Used/total size (Byte) of a memory heap
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HeapUsage {
+    pub used: u64,
+    pub total: u64,
+    /// `used / total * 100`, 0 if `total` is 0
+    pub percent: f64,
+}
+
+impl HeapUsage {
+    pub fn new(used: u64, total: u64) -> Self {
+        let percent = if total == 0 { 0.0 } else { used as f64 / total as f64 * 100.0 };
+
+        Self { used, total, percent }
+    }
+}
+
+/// VRAM, CPU-accessible (visible) VRAM and GTT usage
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MemoryUsage {
+    pub vram: HeapUsage,
+    pub cpu_accessible_vram: HeapUsage,
+    pub gtt: HeapUsage,
+}
+
+/// Named heap sizes of [drm_amdgpu_memory_info], Byte
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemoryBreakdown {
+    /// Total VRAM size (`vram.total_heap_size`), fixed
+    pub vram_total: u64,
+    /// VRAM available for allocation (`vram.usable_heap_size`),
+    /// `real_size - pin_size - reserved_size`, varies at runtime
+    pub vram_usable: u64,
+    /// Total CPU-accessible (visible) VRAM size (`cpu_accessible_vram.total_heap_size`), fixed
+    pub visible_total: u64,
+    /// CPU-accessible VRAM available for allocation (`cpu_accessible_vram.usable_heap_size`),
+    /// varies at runtime
+    pub visible_usable: u64,
+    /// Total GTT size (`gtt.total_heap_size`), fixed
+    pub gtt_total: u64,
+    /// GTT available for allocation (`gtt.usable_heap_size`), varies at runtime
+    pub gtt_usable: u64,
+}
+
+impl MemoryBreakdown {
+    /// Byte to GiB
+    pub fn to_gib(bytes: u64) -> f64 {
+        bytes as f64 / (1u64 << 30) as f64
+    }
+}
+
+impl drm_amdgpu_memory_info {
+    pub fn breakdown(&self) -> MemoryBreakdown {
+        MemoryBreakdown {
+            vram_total: self.vram.total_heap_size,
+            vram_usable: self.vram.usable_heap_size,
+            visible_total: self.cpu_accessible_vram.total_heap_size,
+            visible_usable: self.cpu_accessible_vram.usable_heap_size,
+            gtt_total: self.gtt.total_heap_size,
+            gtt_usable: self.gtt.usable_heap_size,
+        }
+    }
+}
+
+impl DeviceHandle {
+    /// Total CPU-accessible (visible) VRAM size (Byte) from sysfs (`mem_info_vis_vram_total`)
+    #[cfg(feature = "std")]
+    pub fn get_vis_vram_total(&self) -> Option<u64> {
+        let sysfs_path = self.get_sysfs_path().ok()?;
+
+        super::parse_hwmon::<u64, _>(sysfs_path.join("mem_info_vis_vram_total"))
+    }
+
+    pub fn get_memory_usage(&self) -> Result<MemoryUsage, i32> {
+        let info = self.memory_info()?;
+
+        Ok(MemoryUsage {
+            vram: HeapUsage::new(self.vram_usage_info()?, info.vram.total_heap_size),
+            cpu_accessible_vram: HeapUsage::new(
+                self.vis_vram_usage_info()?,
+                info.cpu_accessible_vram.total_heap_size,
+            ),
+            gtt: HeapUsage::new(self.gtt_usage_info()?, info.gtt.total_heap_size),
+        })
+    }
+}
+
+/// e.g. "3.2/8.0 GiB (40%)"
+impl fmt::Display for HeapUsage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+            "{:.1}/{:.1} GiB ({:.0}%)",
+            MemoryBreakdown::to_gib(self.used),
+            MemoryBreakdown::to_gib(self.total),
+            self.percent,
+        )
+    }
+}
+
+/// e.g. "VRAM 3.2/8.0 GiB (40%), Visible VRAM 0.1/0.2 GiB (50%), GTT 0.5/16.0 GiB (3%)"
+impl fmt::Display for MemoryUsage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+            "VRAM {}, Visible VRAM {}, GTT {}",
+            self.vram,
+            self.cpu_accessible_vram,
+            self.gtt,
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_memory_usage_display() {
+    const GIB: u64 = 1 << 30;
+    let vram = HeapUsage::new(GIB * 2, GIB * 8);
+
+    assert_eq!(vram.percent, 25.0);
+    assert_eq!(vram.to_string(), "2.0/8.0 GiB (25%)");
+    assert_eq!(HeapUsage::new(GIB * 16 / 5, GIB * 8).to_string(), "3.2/8.0 GiB (40%)");
+    assert_eq!(HeapUsage::new(0, 0).percent, 0.0);
+}
+
+#[test]
+fn test_memory_breakdown() {
+    let mut info: drm_amdgpu_memory_info = unsafe { core::mem::zeroed() };
+
+    info.vram.total_heap_size = 8 << 30;
+    info.vram.usable_heap_size = 7 << 30;
+    info.cpu_accessible_vram.total_heap_size = 256 << 20;
+    info.gtt.usable_heap_size = 15 << 30;
+
+    let b = info.breakdown();
+
+    assert_eq!(b.vram_total, 8 << 30);
+    assert_eq!(b.vram_usable, 7 << 30);
+    assert_eq!(b.visible_total, 256 << 20);
+    assert_eq!(b.gtt_usable, 15 << 30);
+    assert_eq!(MemoryBreakdown::to_gib(b.visible_total), 0.25);
+}
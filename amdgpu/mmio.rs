@@ -0,0 +1,188 @@
+use crate::AMDGPU::{
+    DeviceHandle,
+    CHIP_CLASS,
+    CP_STAT_OFFSET,
+    GRBM_OFFSET,
+    GRBM2_OFFSET,
+    SRBM_OFFSET,
+    SRBM2_OFFSET,
+};
+use crate::bindings::{
+    AMDGPU_INFO_MMR_SE_INDEX_MASK,
+    AMDGPU_INFO_MMR_SE_INDEX_SHIFT,
+    AMDGPU_INFO_MMR_SH_INDEX_MASK,
+    AMDGPU_INFO_MMR_SH_INDEX_SHIFT,
+};
+
+/// Index for [mmr_instance_mask] to broadcast to all SEs/SHs
+pub const MMR_INDEX_BROADCAST: u32 = 0xFF;
+
+/// Build the `instance` argument of `amdgpu_read_mm_registers` (`AMDGPU_INFO_READ_MMR_REG`)
+/// selecting a shader engine (SE) and a shader array (SH).
+///
+/// | bits  | field                                   |
+/// |-------|-----------------------------------------|
+/// | 0-7   | SE index (`AMDGPU_INFO_MMR_SE_INDEX_*`) |
+/// | 8-15  | SH index (`AMDGPU_INFO_MMR_SH_INDEX_*`) |
+/// | 16-31 | must be `0`                             |
+///
+/// An index of `0xFF` ([MMR_INDEX_BROADCAST]) broadcasts to all SEs/SHs.
+/// `0xFFFF_FFFF` (all bits set) selects all instances, without switching the GRBM index.
+/// The kernel returns `-EINVAL` if the SE index is `AMDGPU_GFX_MAX_SE` (`4`) or more,
+/// or the SH index is `AMDGPU_GFX_MAX_SH_PER_SE` (`2`) or more,
+/// regardless of the number of SEs/SHs of the ASIC.
+pub const fn mmr_instance_mask(se: u32, sh: u32) -> u32 {
+    ((se & AMDGPU_INFO_MMR_SE_INDEX_MASK) << AMDGPU_INFO_MMR_SE_INDEX_SHIFT)
+        | ((sh & AMDGPU_INFO_MMR_SH_INDEX_MASK) << AMDGPU_INFO_MMR_SH_INDEX_SHIFT)
+}
+
+/// Registers commonly inspected with [DeviceHandle::read_register].
+/// Only registers in the allowed list of the kernel (e.g. `nv_allowed_read_registers`) can be read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MmioRegister {
+    GRBM_STATUS,
+    GRBM_STATUS2,
+    /// GFX6-GFX8
+    SRBM_STATUS,
+    /// GFX6-GFX8
+    SRBM_STATUS2,
+    CP_STAT,
+}
+
+impl MmioRegister {
+    /// DWORD offset of the register for [CHIP_CLASS],
+    /// `None` if the register does not exist or is not readable on it.
+    /// From GFX9, the registers are at `GC_BASE + offset`,
+    /// which results in the same absolute offsets as GFX6-GFX8 for the GRBM/CP registers.
+    pub fn offset(&self, chip_class: CHIP_CLASS) -> Option<u32> {
+        if chip_class < CHIP_CLASS::GFX6 {
+            return None;
+        }
+
+        let offset = match self {
+            Self::GRBM_STATUS => GRBM_OFFSET,
+            Self::GRBM_STATUS2 => GRBM2_OFFSET,
+            Self::SRBM_STATUS if chip_class <= CHIP_CLASS::GFX8 => SRBM_OFFSET,
+            Self::SRBM_STATUS2 if chip_class <= CHIP_CLASS::GFX8 => SRBM2_OFFSET,
+            Self::SRBM_STATUS | Self::SRBM_STATUS2 => return None,
+            Self::CP_STAT => CP_STAT_OFFSET,
+        };
+
+        Some(offset)
+    }
+}
+
+/// Decoder for `GRBM_STATUS`, only the bits common to GFX6-GFX12
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GrbmStatus(pub u32);
+
+impl GrbmStatus {
+    const fn bit(&self, shift: u32) -> bool {
+        (self.0 >> shift) & 0b1 == 1
+    }
+
+    /// Texture Addresser
+    pub const fn ta_busy(&self) -> bool {
+        self.bit(14)
+    }
+
+    /// Global Data Share
+    pub const fn gds_busy(&self) -> bool {
+        self.bit(15)
+    }
+
+    /// Shader Export
+    pub const fn sx_busy(&self) -> bool {
+        self.bit(20)
+    }
+
+    /// Shader Processor Interpolator
+    pub const fn spi_busy(&self) -> bool {
+        self.bit(22)
+    }
+
+    /// Barycentric Interpolation
+    pub const fn bci_busy(&self) -> bool {
+        self.bit(23)
+    }
+
+    /// Scan Converter
+    pub const fn sc_busy(&self) -> bool {
+        self.bit(24)
+    }
+
+    /// Primitive Assembly
+    pub const fn pa_busy(&self) -> bool {
+        self.bit(25)
+    }
+
+    /// Depth Block
+    pub const fn db_busy(&self) -> bool {
+        self.bit(26)
+    }
+
+    /// Command Processor
+    pub const fn cp_busy(&self) -> bool {
+        self.bit(29)
+    }
+
+    /// Color Block
+    pub const fn cb_busy(&self) -> bool {
+        self.bit(30)
+    }
+
+    /// Any of the graphics pipeline is busy
+    pub const fn gui_active(&self) -> bool {
+        self.bit(31)
+    }
+}
+
+impl From<u32> for GrbmStatus {
+    fn from(val: u32) -> Self {
+        Self(val)
+    }
+}
+
+impl DeviceHandle {
+    /// Read [MmioRegister] at the offset for the ASIC of the device.
+    /// Returns `Err(-EINVAL)` if the register is not available on the ASIC.
+    pub fn read_register(&self, reg: MmioRegister) -> Result<u32, i32> {
+        let chip_class = self.get_asic_name()?.chip_class();
+        let offset = reg.offset(chip_class).ok_or(-libc::EINVAL)?;
+
+        self.read_mm_registers(offset)
+    }
+
+    pub fn read_grbm_status(&self) -> Result<GrbmStatus, i32> {
+        self.read_register(MmioRegister::GRBM_STATUS).map(GrbmStatus)
+    }
+
+    /// Read the register at `offset` of the shader engine `se` and the shader array `sh`,
+    /// see [mmr_instance_mask] for the encoding.
+    pub fn read_mm_register_per_instance(&self, offset: u32, se: u32, sh: u32) -> Result<u32, i32> {
+        let mut out = [0u32; 1];
+
+        self.read_mm_registers_to_slice(offset, mmr_instance_mask(se, sh), &mut out)?;
+
+        Ok(out[0])
+    }
+}
+
+#[test]
+fn test_mmio_register() {
+    assert_eq!(MmioRegister::GRBM_STATUS.offset(CHIP_CLASS::GFX11), Some(0x2004));
+    assert_eq!(MmioRegister::SRBM_STATUS.offset(CHIP_CLASS::GFX8), Some(0x394));
+    assert_eq!(MmioRegister::SRBM_STATUS.offset(CHIP_CLASS::GFX9), None);
+    assert_eq!(MmioRegister::CP_STAT.offset(CHIP_CLASS::CAYMAN), None);
+
+    assert_eq!(mmr_instance_mask(1, 0), 0x0001);
+    assert_eq!(mmr_instance_mask(2, 1), 0x0102);
+    assert_eq!(mmr_instance_mask(MMR_INDEX_BROADCAST, 1), 0x01FF);
+    assert_eq!(mmr_instance_mask(0x1FF, 0x100), 0x00FF);
+
+    let grbm = GrbmStatus(0x8000_4000);
+
+    assert!(grbm.gui_active());
+    assert!(grbm.ta_busy());
+    assert!(!grbm.cp_busy());
+}
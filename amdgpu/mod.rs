@@ -28,9 +28,39 @@ pub use asic::*;
 mod chip_class;
 pub use chip_class::*;
 
+mod asic_capabilities;
+pub use asic_capabilities::*;
+
+mod cache_hierarchy;
+pub use cache_hierarchy::*;
+
+mod memory_usage;
+pub use memory_usage::*;
+
+mod eviction_tracker;
+pub use eviction_tracker::*;
+
+mod gds_info;
+pub use gds_info::*;
+
+mod capability_summary;
+pub use capability_summary::*;
+
+mod peak_throughput;
+pub use peak_throughput::*;
+
+mod state_snapshot;
+pub use state_snapshot::*;
+
+mod mmio;
+pub use mmio::*;
+
 mod gpu_info;
 pub use gpu_info::*;
 
+mod ids_flags;
+pub use ids_flags::{DeviceIdsFlags, Capabilities};
+
 mod gfx_target_version;
 pub use gfx_target_version::GfxTargetVersion;
 
@@ -57,6 +87,11 @@ mod hwmon_temp;
 #[cfg(feature = "std")]
 pub use hwmon_temp::*;
 
+#[cfg(feature = "std")]
+mod hbm_info;
+#[cfg(feature = "std")]
+pub use hbm_info::*;
+
 #[cfg(feature = "std")]
 mod power_cap;
 #[cfg(feature = "std")]
@@ -67,11 +102,26 @@ mod power_profile;
 #[cfg(feature = "std")]
 pub use power_profile::*;
 
+#[cfg(feature = "std")]
+mod dpm_level;
+#[cfg(feature = "std")]
+pub use dpm_level::*;
+
 #[cfg(feature = "std")]
 mod dpm_forced_level;
 #[cfg(feature = "std")]
 pub use dpm_forced_level::*;
 
+#[cfg(feature = "std")]
+mod od_clk_voltage;
+#[cfg(feature = "std")]
+pub use od_clk_voltage::*;
+
+#[cfg(feature = "std")]
+mod fan_curve;
+#[cfg(feature = "std")]
+pub use fan_curve::*;
+
 #[cfg(feature = "std")]
 mod ip_discovery;
 #[cfg(feature = "std")]
@@ -82,6 +132,71 @@ mod ras_features;
 #[cfg(feature = "std")]
 pub use ras_features::*;
 
+#[cfg(feature = "std")]
+mod sensor_snapshot;
+#[cfg(feature = "std")]
+pub use sensor_snapshot::*;
+
+#[cfg(feature = "std")]
+mod activity_info;
+#[cfg(feature = "std")]
+pub use activity_info::*;
+
+#[cfg(feature = "std")]
+mod pp_features;
+#[cfg(feature = "std")]
+pub use pp_features::*;
+
+#[cfg(feature = "std")]
+mod vce_clock_table;
+#[cfg(feature = "std")]
+pub use vce_clock_table::*;
+
+#[cfg(feature = "std")]
+mod current_clocks;
+#[cfg(feature = "std")]
+pub use current_clocks::*;
+
+#[cfg(feature = "std")]
+mod board_info;
+#[cfg(feature = "std")]
+pub use board_info::*;
+
+#[cfg(feature = "std")]
+mod clock_gating;
+#[cfg(feature = "std")]
+pub use clock_gating::*;
+
+#[cfg(feature = "std")]
+mod wait_idle;
+#[cfg(feature = "std")]
+pub use wait_idle::*;
+
+#[cfg(feature = "std")]
+mod gpu_info_consistency;
+#[cfg(feature = "std")]
+pub use gpu_info_consistency::*;
+
+#[cfg(feature = "std")]
+mod gpu_process;
+#[cfg(feature = "std")]
+pub use gpu_process::*;
+
+#[cfg(feature = "std")]
+mod engine_usage;
+#[cfg(feature = "std")]
+pub use engine_usage::*;
+
+#[cfg(feature = "std")]
+mod xgmi_info;
+#[cfg(feature = "std")]
+pub use xgmi_info::*;
+
+#[cfg(feature = "std")]
+mod device_report;
+#[cfg(feature = "std")]
+pub use device_report::*;
+
 #[cfg(feature = "std")]
 pub(crate) fn parse_hwmon<T: std::str::FromStr, P: Into<std::path::PathBuf>>(path: P) -> Option<T> {
     std::fs::read_to_string(path.into()).ok()
@@ -89,7 +204,10 @@ pub(crate) fn parse_hwmon<T: std::str::FromStr, P: Into<std::path::PathBuf>>(pat
 }
 
 mod vbios;
+#[cfg(feature = "std")]
 mod vbios_parser;
+#[cfg(feature = "std")]
+mod vbios_image;
 mod video_caps;
 mod hw_ip;
 mod fw_version;
@@ -98,8 +216,12 @@ pub mod pp_table;
 pub use pp_table::PPTable;
 
 pub mod VBIOS {
+    #[cfg(feature = "std")]
     pub use super::vbios::*;
+    #[cfg(feature = "std")]
     pub use super::vbios_parser::*;
+    #[cfg(feature = "std")]
+    pub use super::vbios_image::*;
 }
 
 /// # Video Encode/Decode Capabilities
@@ -0,0 +1,367 @@
+use crate::AMDGPU::{DeviceHandle, DpmForcedLevel};
+use std::io;
+use std::path::PathBuf;
+
+const FILE_NAME: &str = "pp_od_clk_voltage";
+
+/// Layout of `pp_od_clk_voltage`
+/// ref: <https://www.kernel.org/doc/html/latest/gpu/amdgpu/thermal.html#pp-od-clk-voltage>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OdClkVoltageType {
+    /// Each SCLK/MCLK level has a voltage (Vega10 or older)
+    VoltagePoints,
+    /// `OD_VDDC_CURVE` (Vega20, Navi1x)
+    VddcCurve,
+    /// min/max clock and `OD_VDDGFX_OFFSET` (Navi2x or later)
+    VddgfxOffset,
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OdClockLevel {
+    pub index: u32,
+    /// MHz
+    pub clock: u32,
+    /// mV
+    pub voltage: Option<u32>,
+}
+
+/// `[min, max]` ranges from `OD_RANGE`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OdRange {
+    /// MHz
+    pub sclk: Option<[u32; 2]>,
+    /// MHz
+    pub mclk: Option<[u32; 2]>,
+    /// mV
+    pub vddc: Option<[u32; 2]>,
+    /// MHz, `VDDC_CURVE_SCLK[#]`
+    pub vddc_curve_sclk: Vec<[u32; 2]>,
+    /// mV, `VDDC_CURVE_VOLT[#]`
+    pub vddc_curve_volt: Vec<[u32; 2]>,
+    /// mV, `VDDGFX_OFFSET` (Navi3x or later)
+    pub vddgfx_offset: Option<[i32; 2]>,
+}
+
+/// Current OverDrive state parsed from `pp_od_clk_voltage`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OdClkVoltage {
+    pub type_: OdClkVoltageType,
+    pub sclk: Vec<OdClockLevel>,
+    pub mclk: Vec<OdClockLevel>,
+    pub vddc_curve: Vec<OdClockLevel>,
+    /// mV
+    pub vddgfx_offset: Option<i32>,
+    pub range: OdRange,
+}
+
+/// Points of `OD_VDDC_CURVE` and their ranges from `OD_RANGE` (Vega20, Navi1x)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VddcCurve {
+    /// (MHz, mV)
+    pub points: Vec<(u32, u32)>,
+    /// MHz, `[min, max]` of each point
+    pub sclk_range: Vec<[u32; 2]>,
+    /// mV, `[min, max]` of each point
+    pub volt_range: Vec<[u32; 2]>,
+}
+
+impl DeviceHandle {
+    pub fn get_od_clk_voltage(&self) -> io::Result<OdClkVoltage> {
+        let sysfs_path = self.get_sysfs_path()
+            .map_err(|_| io::Error::from(io::ErrorKind::NotFound))?;
+
+        OdClkVoltage::get_from_sysfs(sysfs_path)
+    }
+
+    /// Get the voltage curve points, (MHz, mV), from `OD_VDDC_CURVE` of `pp_od_clk_voltage`.  
+    /// Returns `None` if the card uses a different OverDrive scheme
+    /// (per-level voltage on Vega10 or older, `OD_VDDGFX_OFFSET` on Navi2x or later).
+    pub fn get_vddc_curve(&self) -> Option<Vec<(u32, u32)>> {
+        self.get_vddc_curve_with_range().map(|curve| curve.points)
+    }
+
+    /// [DeviceHandle::get_vddc_curve] with the ranges of each point from `OD_RANGE`
+    pub fn get_vddc_curve_with_range(&self) -> Option<VddcCurve> {
+        self.get_od_clk_voltage().ok()?.vddc_curve()
+    }
+
+    /// Set the SCLK of the OverDrive level, clamped to `OD_RANGE`.
+    /// On Navi2x or later, level 0/1 are the min/max SCLK.  
+    /// The change is applied by [DeviceHandle::commit_od].
+    /// Requires root privileges and [DpmForcedLevel::Manual].
+    pub fn set_od_sclk(&self, level: u8, mhz: u32) -> io::Result<()> {
+        let od = self.get_od_clk_voltage()?;
+
+        self.write_od_command(&od.sclk_command(level, mhz))
+    }
+
+    /// Set the MCLK of the OverDrive level, clamped to `OD_RANGE`.
+    /// The change is applied by [DeviceHandle::commit_od].
+    /// Requires root privileges and [DpmForcedLevel::Manual].
+    pub fn set_od_mclk(&self, level: u8, mhz: u32) -> io::Result<()> {
+        let od = self.get_od_clk_voltage()?;
+
+        self.write_od_command(&od.mclk_command(level, mhz))
+    }
+
+    /// Set `OD_VDDGFX_OFFSET` (mV, Navi2x or later).
+    /// Returns `ErrorKind::InvalidInput` if `mv` is out of `VDDGFX_OFFSET` of `OD_RANGE`
+    /// (Navi2x does not report the range, the kernel validates it).  
+    /// The change is applied by [DeviceHandle::commit_od].
+    /// Requires root privileges and [DpmForcedLevel::Manual].
+    pub fn set_od_vddgfx_offset(&self, mv: i32) -> io::Result<()> {
+        let od = self.get_od_clk_voltage()?;
+        let cmd = od.vddgfx_offset_command(mv).ok_or(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "VDDGFX offset is out of OD_RANGE",
+        ))?;
+
+        self.write_od_command(&cmd)
+    }
+
+    /// Commit the OverDrive changes (write `c` to `pp_od_clk_voltage`).
+    /// Requires root privileges and [DpmForcedLevel::Manual].
+    pub fn commit_od(&self) -> io::Result<()> {
+        self.write_od_command("c")
+    }
+
+    fn write_od_command(&self, cmd: &str) -> io::Result<()> {
+        let sysfs_path = self.get_sysfs_path()
+            .map_err(|_| io::Error::from(io::ErrorKind::NotFound))?;
+
+        if DpmForcedLevel::get_from_sysfs(&sysfs_path)? != DpmForcedLevel::Manual {
+            return Err(io::Error::other("power_dpm_force_performance_level is not manual"));
+        }
+
+        std::fs::write(sysfs_path.join(FILE_NAME), cmd)
+    }
+}
+
+impl OdClkVoltage {
+    pub fn get_from_sysfs<P: Into<PathBuf>>(sysfs_path: P) -> io::Result<Self> {
+        let s = std::fs::read_to_string(sysfs_path.into().join(FILE_NAME))?;
+
+        Self::parse(&s).ok_or(io::Error::other("Parse Error"))
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        let mut sclk = Vec::new();
+        let mut mclk = Vec::new();
+        let mut vddc_curve = Vec::new();
+        let mut vddgfx_offset = None;
+        let mut range = OdRange::default();
+        let mut section = "";
+
+        for line in s.lines() {
+            let line = line.trim();
+
+            if line.is_empty() { continue }
+
+            if line.starts_with("OD_") && line.ends_with(':') {
+                section = line.trim_end_matches(':');
+                continue;
+            }
+
+            match section {
+                "OD_SCLK" => sclk.push(parse_level(line)?),
+                "OD_MCLK" => mclk.push(parse_level(line)?),
+                "OD_VDDC_CURVE" => vddc_curve.push(parse_level(line)?),
+                "OD_VDDGFX_OFFSET" => vddgfx_offset = line.strip_suffix("mV")?.parse().ok(),
+                "OD_RANGE" => range.parse_line(line),
+                _ => {},
+            }
+        }
+
+        let type_ = if !vddc_curve.is_empty() {
+            OdClkVoltageType::VddcCurve
+        } else if vddgfx_offset.is_some() {
+            OdClkVoltageType::VddgfxOffset
+        } else if sclk.iter().any(|l| l.voltage.is_some()) {
+            OdClkVoltageType::VoltagePoints
+        } else {
+            OdClkVoltageType::Unknown
+        };
+
+        Some(Self { type_, sclk, mclk, vddc_curve, vddgfx_offset, range })
+    }
+
+    /// Returns `None` unless [OdClkVoltageType::VddcCurve]
+    pub fn vddc_curve(&self) -> Option<VddcCurve> {
+        if self.type_ != OdClkVoltageType::VddcCurve { return None }
+
+        let points = self.vddc_curve
+            .iter()
+            .map(|l| l.voltage.map(|v| (l.clock, v)))
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(VddcCurve {
+            points,
+            sclk_range: self.range.vddc_curve_sclk.clone(),
+            volt_range: self.range.vddc_curve_volt.clone(),
+        })
+    }
+
+    /// `s <level> <MHz> [<mV>]`, the current voltage of the level is kept
+    /// for [OdClkVoltageType::VoltagePoints]
+    pub fn sclk_command(&self, level: u8, mhz: u32) -> String {
+        level_command("s", &self.sclk, self.range.sclk, level, mhz)
+    }
+
+    /// `m <level> <MHz> [<mV>]`, the current voltage of the level is kept
+    /// for [OdClkVoltageType::VoltagePoints]
+    pub fn mclk_command(&self, level: u8, mhz: u32) -> String {
+        level_command("m", &self.mclk, self.range.mclk, level, mhz)
+    }
+
+    /// `vo <mV>`, `None` if `mv` is out of `VDDGFX_OFFSET` of `OD_RANGE`
+    pub fn vddgfx_offset_command(&self, mv: i32) -> Option<String> {
+        if let Some([min, max]) = self.range.vddgfx_offset {
+            if !(min..=max).contains(&mv) { return None }
+        }
+
+        Some(format!("vo {mv}"))
+    }
+}
+
+fn level_command(
+    cmd: &str,
+    levels: &[OdClockLevel],
+    range: Option<[u32; 2]>,
+    level: u8,
+    mhz: u32,
+) -> String {
+    let mhz = match range {
+        Some([min, max]) => mhz.clamp(min, max),
+        None => mhz,
+    };
+    let voltage = levels
+        .iter()
+        .find(|l| l.index == level as u32)
+        .and_then(|l| l.voltage);
+
+    match voltage {
+        Some(mv) => format!("{cmd} {level} {mhz} {mv}"),
+        None => format!("{cmd} {level} {mhz}"),
+    }
+}
+
+impl OdRange {
+    fn parse_line(&mut self, line: &str) {
+        let mut split = line.split_whitespace();
+        let Some(name) = split.next() else { return };
+
+        // "VDDGFX_OFFSET:    -450mv         0mv"
+        if name == "VDDGFX_OFFSET:" {
+            let [min, max] = [split.next(), split.next()].map(|v| {
+                let v = v?;
+
+                v.strip_suffix("mV").or_else(|| v.strip_suffix("mv"))?.parse::<i32>().ok()
+            });
+
+            if let (Some(min), Some(max)) = (min, max) {
+                self.vddgfx_offset = Some([min, max]);
+            }
+
+            return;
+        }
+
+        let [min, max] = [split.next(), split.next()]
+            .map(|v| v.and_then(|v| parse_mhz(v).or_else(|| parse_mv(v))));
+        let (Some(min), Some(max)) = (min, max) else { return };
+        let v = [min, max];
+
+        match name.trim_end_matches(':') {
+            "SCLK" => self.sclk = Some(v),
+            "MCLK" => self.mclk = Some(v),
+            "VDDC" => self.vddc = Some(v),
+            n if n.starts_with("VDDC_CURVE_SCLK") => self.vddc_curve_sclk.push(v),
+            n if n.starts_with("VDDC_CURVE_VOLT") => self.vddc_curve_volt.push(v),
+            _ => {},
+        }
+    }
+}
+
+/// "0: 700Mhz", "0:        300MHz        800mV", "0: 800MHz @ 711mV" (Navi1x)
+fn parse_level(line: &str) -> Option<OdClockLevel> {
+    let mut split = line.split_whitespace();
+    let index = split.next()?.trim_end_matches(':').parse().ok()?;
+    let clock = parse_mhz(split.next()?)?;
+    let voltage = split.find(|s| *s != "@").and_then(parse_mv);
+
+    Some(OdClockLevel { index, clock, voltage })
+}
+
+fn parse_mhz(s: &str) -> Option<u32> {
+    s.strip_suffix("MHz").or_else(|| s.strip_suffix("Mhz"))?.parse().ok()
+}
+
+fn parse_mv(s: &str) -> Option<u32> {
+    s.strip_suffix("mV")?.parse().ok()
+}
+
+#[test]
+fn test_parse_od_clk_voltage() {
+    let vega20 = "OD_SCLK:\n0: 700Mhz\n1: 1800Mhz\nOD_MCLK:\n1: 1000Mhz\n\
+        OD_VDDC_CURVE:\n0: 700Mhz 800mV\n1: 1250Mhz 870mV\n2: 1800Mhz 1050mV\n\
+        OD_RANGE:\nSCLK:     700Mhz       2200Mhz\nMCLK:     800Mhz       1200Mhz\n\
+        VDDC_CURVE_SCLK[0]:     700Mhz       2200Mhz\nVDDC_CURVE_VOLT[0]:     750mV        1200mV\n";
+    let od = OdClkVoltage::parse(vega20).unwrap();
+
+    assert_eq!(od.type_, OdClkVoltageType::VddcCurve);
+    assert_eq!(od.sclk[1], OdClockLevel { index: 1, clock: 1800, voltage: None });
+    assert_eq!(od.vddc_curve[2], OdClockLevel { index: 2, clock: 1800, voltage: Some(1050) });
+    assert_eq!(od.range.sclk, Some([700, 2200]));
+    assert_eq!(od.range.vddc_curve_volt, vec![[750, 1200]]);
+    assert_eq!(od.vddc_curve().unwrap().points, [(700, 800), (1250, 870), (1800, 1050)]);
+    assert_eq!(od.vddc_curve().unwrap().sclk_range, [[700, 2200]]);
+
+    let navi21 = "OD_SCLK:\n0: 500Mhz\n1: 2800Mhz\nOD_MCLK:\n0: 97Mhz\n1: 1000MHz\n\
+        OD_VDDGFX_OFFSET:\n-50mV\nOD_RANGE:\nSCLK:     500Mhz       3150Mhz\nMCLK:     674Mhz       1200Mhz\n";
+    let od = OdClkVoltage::parse(navi21).unwrap();
+
+    assert_eq!(od.type_, OdClkVoltageType::VddgfxOffset);
+    assert_eq!(od.vddgfx_offset, Some(-50));
+    assert_eq!(od.mclk[1].clock, 1000);
+    assert_eq!(od.range.mclk, Some([674, 1200]));
+    assert_eq!(od.vddc_curve(), None);
+    assert_eq!(od.sclk_command(1, 3500), "s 1 3150");
+    assert_eq!(od.mclk_command(1, 1100), "m 1 1100");
+    assert_eq!(od.vddgfx_offset_command(-500).as_deref(), Some("vo -500"));
+
+    let navi31 = "OD_SCLK:\n0: 500Mhz\n1: 2500Mhz\nOD_MCLK:\n0: 97Mhz\n1: 1250MHz\n\
+        OD_VDDGFX_OFFSET:\n0mV\nOD_RANGE:\nSCLK:     500Mhz       3000Mhz\nMCLK:      97Mhz       1500Mhz\n\
+        VDDGFX_OFFSET:    -450mv         0mv\n";
+    let od = OdClkVoltage::parse(navi31).unwrap();
+
+    assert_eq!(od.range.vddgfx_offset, Some([-450, 0]));
+    assert_eq!(od.vddgfx_offset_command(-100).as_deref(), Some("vo -100"));
+    assert_eq!(od.vddgfx_offset_command(-500), None);
+    assert_eq!(od.vddgfx_offset_command(50), None);
+
+    let vega10 = "OD_SCLK:\n0:        852Mhz        800mV\n1:        991Mhz        900mV\n\
+        OD_MCLK:\n0:        167Mhz        800mV\n\
+        OD_RANGE:\nSCLK:     852Mhz       2400Mhz\nMCLK:     167Mhz       1500Mhz\n";
+    let od = OdClkVoltage::parse(vega10).unwrap();
+
+    assert_eq!(od.sclk_command(1, 500), "s 1 852 900");
+    assert_eq!(
+        parse_level("2: 2100MHz @ 1191mV"),
+        Some(OdClockLevel { index: 2, clock: 2100, voltage: Some(1191) }),
+    );
+}
+
+#[test]
+fn test_vddc_curve_navi10() {
+    let navi10 = "OD_SCLK:\n0: 800Mhz\n1: 2100Mhz\nOD_MCLK:\n1: 875MHz\n\
+        OD_VDDC_CURVE:\n0: 800MHz @ 711mV\n1: 1450MHz @ 801mV\n2: 2100MHz @ 1191mV\n\
+        OD_RANGE:\nSCLK:     800Mhz       2150Mhz\nMCLK:     625Mhz        950Mhz\n\
+        VDDC_CURVE_SCLK[0]:     800Mhz       2150Mhz\nVDDC_CURVE_VOLT[0]:     750mV        1200mV\n\
+        VDDC_CURVE_SCLK[1]:     800Mhz       2150Mhz\nVDDC_CURVE_VOLT[1]:     750mV        1200mV\n\
+        VDDC_CURVE_SCLK[2]:     800Mhz       2150Mhz\nVDDC_CURVE_VOLT[2]:     750mV        1200mV\n";
+    let curve = OdClkVoltage::parse(navi10).unwrap().vddc_curve().unwrap();
+
+    assert_eq!(curve.points, [(800, 711), (1450, 801), (2100, 1191)]);
+    assert_eq!(curve.sclk_range, [[800, 2150]; 3]);
+    assert_eq!(curve.volt_range, [[750, 1200]; 3]);
+}
@@ -0,0 +1,95 @@
+use crate::AMDGPU::{ASIC_NAME, CHIP_CLASS, DeviceHandle, GPU_INFO};
+
+/// Theoretical peak throughput, GFLOPS (GOPS for `int8_dot`).
+///
+/// `fp32 = CUs * lanes per CU * 2 (FMA) * max sclk`
+/// * lanes per CU: `num_simd_per_cu * SIMD width` (GCN: 4 * 16, RDNA: 2 * 32)
+/// * GFX11 (RDNA 3) and later: x2 for dual-issue VALU
+/// * `fp16`: x2 of `fp32` if packed math is supported, else same as `fp32`
+/// * `int8_dot`: x4 of `fp32` (without dual-issue) with `V_DOT4_I32_I8`
+///
+/// Real throughput is lower, it depends on the sustained clock (power/thermal limits),
+/// the instruction mix and whether the compiler can use dual-issue/packed instructions.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PeakThroughput {
+    pub fp32: f64,
+    pub fp16: f64,
+    /// `None` if accelerated dot product instructions are not supported
+    pub int8_dot: Option<f64>,
+}
+
+impl PeakThroughput {
+    pub fn new(asic_name: ASIC_NAME, cu_count: u32, max_sclk_mhz: u32) -> Self {
+        let simd_width = if asic_name >= ASIC_NAME::CHIP_NAVI10 { 32 } else { 16 };
+        let lanes = asic_name.num_simd_per_cu() as u32 * simd_width;
+        let base = cu_count as f64 * lanes as f64 * 2.0 * max_sclk_mhz as f64 / 1000.0;
+        let dual_issue = if asic_name.chip_class() >= CHIP_CLASS::GFX11 { 2.0 } else { 1.0 };
+        let fp32 = base * dual_issue;
+        let fp16 = if asic_name.has_packed_math_16bit() { fp32 * 2.0 } else { fp32 };
+        let int8_dot = asic_name.has_accelerated_dot_product().then_some(base * 4.0);
+
+        Self { fp32, fp16, int8_dot }
+    }
+}
+
+impl ASIC_NAME {
+    /// Theoretical maximum of the concurrent wavefronts on `cu_count` CUs,
+    /// `cu_count * num_simd_per_cu * max_wave64_per_simd`.  
+    /// On RDNA, a wave32 and a wave64 occupy one wave slot each,
+    /// so the number of work-items in flight is `waves * wave size` ([ASIC_NAME::native_wave_size] or 64).
+    pub fn max_waves_in_flight(&self, cu_count: u32) -> u32 {
+        cu_count * self.num_simd_per_cu() as u32 * self.max_wave64_per_simd() as u32
+    }
+}
+
+impl DeviceHandle {
+    /// Theoretical maximum of the concurrent wavefronts across the whole GPU,
+    /// from the active CU count of [DeviceHandle::device_info] (harvested CUs are excluded).  
+    /// Assumes full occupancy: every wave slot of every SIMD is filled,
+    /// i.e. VGPR/SGPR, LDS and scratch usage of the shader do not limit the occupancy.
+    /// See [ASIC_NAME::max_waves_in_flight].
+    pub fn max_waves_in_flight(&self) -> Result<u32, i32> {
+        let info = self.device_info()?;
+
+        Ok(info.get_asic_name().max_waves_in_flight(info.cu_active_number()))
+    }
+
+    /// Estimate [PeakThroughput] from the active CU count and the max sclk of [DeviceHandle::device_info]
+    pub fn estimate_peak_gflops(&self) -> Result<PeakThroughput, i32> {
+        let info = self.device_info()?;
+        let max_sclk_mhz = (info.max_engine_clock() / 1000) as u32;
+
+        Ok(PeakThroughput::new(info.get_asic_name(), info.cu_active_number(), max_sclk_mhz))
+    }
+}
+
+#[test]
+fn test_peak_throughput() {
+    /* RX 6900 XT: 80 CU, 2250 MHz */
+    let navi21 = PeakThroughput::new(ASIC_NAME::CHIP_NAVI21, 80, 2250);
+
+    assert_eq!(navi21.fp32, 23040.0);
+    assert_eq!(navi21.fp16, 46080.0);
+    assert_eq!(navi21.int8_dot, Some(92160.0));
+
+    /* RX 7900 XTX: 96 CU, 2500 MHz */
+    let navi31 = PeakThroughput::new(ASIC_NAME::CHIP_GFX1100, 96, 2500);
+
+    assert_eq!(navi31.fp32, 61440.0);
+    assert_eq!(navi31.fp16, 122880.0);
+
+    /* RX 580: 36 CU, 1340 MHz */
+    let polaris10 = PeakThroughput::new(ASIC_NAME::CHIP_POLARIS10, 36, 1340);
+
+    assert_eq!(polaris10.fp32, 6174.72);
+    assert_eq!(polaris10.fp16, polaris10.fp32);
+    assert_eq!(polaris10.int8_dot, None);
+}
+
+#[test]
+fn test_max_waves_in_flight() {
+    /* Vega 64: 64 CU */
+    assert_eq!(ASIC_NAME::CHIP_VEGA10.max_waves_in_flight(64), 64 * 4 * 10);
+    /* RX 6900 XT: 80 CU */
+    assert_eq!(ASIC_NAME::CHIP_NAVI21.max_waves_in_flight(80), 80 * 2 * 16);
+}
@@ -1,14 +1,45 @@
 use crate::AMDGPU::DeviceHandle;
+use std::io;
 use std::str::FromStr;
 use std::path::PathBuf;
 use super::parse_hwmon;
 
 impl DeviceHandle {
+    /// Power cap (W) from hwmon, see [PowerCap]
     pub fn get_power_cap(&self) -> Option<PowerCap> {
         let hwmon_path = self.get_hwmon_path()?;
 
         PowerCap::from_hwmon_path(hwmon_path)
     }
+
+    /// Current power cap (µW) from hwmon (`power1_cap`)
+    pub fn get_power_cap_uw(&self) -> Option<u32> {
+        let hwmon_path = self.get_hwmon_path()?;
+
+        parse_hwmon::<u32, _>(hwmon_path.join("power1_cap"))
+    }
+
+    /// (min, max) power cap (µW) from hwmon (`power1_cap_min`, `power1_cap_max`)
+    pub fn get_power_cap_range_uw(&self) -> Option<(u32, u32)> {
+        let hwmon_path = self.get_hwmon_path()?;
+        let [min, max] = ["power1_cap_min", "power1_cap_max"]
+            .map(|name| parse_hwmon::<u32, _>(hwmon_path.join(name)));
+
+        Some((min?, max?))
+    }
+
+    /// Set the power cap (µW) to hwmon (`power1_cap`). Requires root privileges.  
+    /// Returns [io::ErrorKind::InvalidInput] if `uw` is out of [Self::get_power_cap_range_uw].
+    pub fn set_power_cap_uw(&self, uw: u32) -> io::Result<()> {
+        let hwmon_path = self.get_hwmon_path()
+            .ok_or(io::Error::from(io::ErrorKind::NotFound))?;
+        let range = self.get_power_cap_range_uw()
+            .ok_or(io::Error::from(io::ErrorKind::NotFound))?;
+
+        PowerCap::check_range_uw(uw, range)?;
+
+        std::fs::write(hwmon_path.join("power1_cap"), uw.to_string())
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -42,6 +73,14 @@ impl PowerCap {
         })
     }
 
+    fn check_range_uw(uw: u32, (min, max): (u32, u32)) -> io::Result<()> {
+        if (min..=max).contains(&uw) {
+            Ok(())
+        } else {
+            Err(io::Error::new(io::ErrorKind::InvalidInput, "Power cap out of range"))
+        }
+    }
+
     /// ref: drivers/gpu/drm/amd/pm/swsmu/smu13/aldebaran_ppt.c
     /// ref: <https://github.com/RadeonOpenCompute/rocm_smi_lib/blob/master/python_smi_tools/rocm_smi.py>
     #[cfg(feature = "std")]
@@ -92,3 +131,13 @@ impl FromStr for PowerCapType {
         }
     }
 }
+
+#[test]
+fn test_power_cap_range() {
+    let range = (100_000_000, 300_000_000);
+
+    assert!(PowerCap::check_range_uw(250_000_000, range).is_ok());
+    assert!(PowerCap::check_range_uw(300_000_000, range).is_ok());
+    assert!(PowerCap::check_range_uw(50_000_000, range).is_err());
+    assert!(PowerCap::check_range_uw(300_000_001, range).is_err());
+}
@@ -45,6 +45,55 @@ impl DeviceHandle {
 
         PowerProfile::get_current_profile_from_sysfs(sysfs_path)
     }
+
+    /// Returns the list of power profile modes in `pp_power_profile_mode`
+    pub fn get_power_profile_modes(&self) -> Vec<PowerProfileMode> {
+        let Ok(sysfs_path) = self.get_sysfs_path() else { return Vec::new() };
+
+        PowerProfileMode::get_from_sysfs(sysfs_path)
+    }
+
+    /// Select the power profile mode by `id`.  
+    /// It requires root privileges and `power_dpm_force_performance_level` to be `manual`.
+    pub fn set_power_profile_mode(&self, id: u32) -> std::io::Result<()> {
+        let sysfs_path = self.get_sysfs_path()
+            .map_err(|_| std::io::Error::from(std::io::ErrorKind::NotFound))?;
+
+        fs::write(sysfs_path.join(FILE_NAME), id.to_string())
+    }
+}
+
+/// An entry of `pp_power_profile_mode`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PowerProfileMode {
+    pub id: u32,
+    pub name: String,
+    pub active: bool,
+}
+
+impl PowerProfileMode {
+    pub fn get_from_sysfs<P: Into<PathBuf>>(sysfs: P) -> Vec<Self> {
+        let sysfs = sysfs.into();
+        let Ok(s) = fs::read_to_string(sysfs.join(FILE_NAME)) else { return Vec::new() };
+
+        Self::parse(&s)
+    }
+
+    /// The header and the columns vary across ASIC generations,
+    /// so only lines whose first token is a numeric id are treated as a profile.  
+    /// Note: The transposed table of SMU v13 is not supported.
+    pub fn parse(s: &str) -> Vec<Self> {
+        s.lines().filter_map(|line| {
+            let mut split = line.split_whitespace();
+            let id = split.next()?.parse::<u32>().ok()?;
+            let name_token = split.next()?;
+            let name = name_token.trim_end_matches([':', '*']).to_string();
+            let active = name_token.contains('*')
+                || split.next().is_some_and(|next| next.starts_with('*'));
+
+            Some(Self { id, name, active })
+        }).collect()
+    }
 }
 
 impl PowerProfile {
@@ -124,3 +173,47 @@ impl fmt::Display for PowerProfile {
         }
     }
 }
+
+#[test]
+fn test_parse_power_profile_mode() {
+    /* Navi21 */
+    let navi21 = "\
+PROFILE_INDEX(NAME) CLOCK_TYPE(NAME) FPS MinActiveFreqType MinActiveFreq BoosterFreqType BoosterFreq PD_Data_limit_c PD_Data_error_coeff PD_Data_error_rate_coeff
+ 0 BOOTUP_DEFAULT :
+                     0(       GFXCLK)       0       5       0       4     800       4194304       3       2      -3
+                     1(       SOCCLK)       0       5       0       4     800       4194304       3       2      -3
+                     2(        MEMLK)       0       5       0       4     800       4194304       3       2      -3
+ 1 3D_FULL_SCREEN*:
+                     0(       GFXCLK)       1       2       1       4    1650       4194304       3       2      -3
+                     1(       SOCCLK)       0       5       0       4    1000       4194304       3       2      -3
+                     2(        MEMLK)       0       5       1       4     850       4194304       3       2      -3
+ 2   POWER_SAVING :
+                     0(       GFXCLK)       0       5       0       4     800       4194304       3       2      -3
+ 5        COMPUTE :
+                     0(       GFXCLK)       0       5       0       4     800       4194304       3       2      -3
+";
+    let modes = PowerProfileMode::parse(navi21);
+
+    assert_eq!(modes.len(), 4);
+    assert_eq!(modes[1], PowerProfileMode { id: 1, name: "3D_FULL_SCREEN".to_string(), active: true });
+    assert_eq!(modes[2], PowerProfileMode { id: 2, name: "POWER_SAVING".to_string(), active: false });
+    assert_eq!(modes.iter().filter(|m| m.active).count(), 1);
+
+    /* Vega10 */
+    let vega10 = "\
+NUM        MODE_NAME BUSY_SET_POINT FPS USE_RLC_BUSY MIN_ACTIVE_LEVEL
+  0 BOOTUP_DEFAULT :             70      60          0              0
+  1 3D_FULL_SCREEN :             70      60          1              3
+  2   POWER_SAVING :             90      60          0              0
+  3          VIDEO :             70      60          0              0
+  4             VR :             70      90          0              0
+  5        COMPUTE*:             30      60          0              6
+  6         CUSTOM :              0       0          0              0
+";
+    let modes = PowerProfileMode::parse(vega10);
+
+    assert_eq!(modes.len(), 7);
+    assert_eq!(modes[5], PowerProfileMode { id: 5, name: "COMPUTE".to_string(), active: true });
+    assert_eq!(modes[6].name, "CUSTOM");
+    assert!(!modes[0].active);
+}
@@ -0,0 +1,176 @@
+use crate::AMDGPU::DeviceHandle;
+use std::path::PathBuf;
+
+/// Mask of enabled PowerPlay features (`pp_features`).
+/// The bit definitions depend on the SMU, use [PpFeatureList] for the names.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PpFeatures(u64);
+
+impl PpFeatures {
+    pub fn new(val: u64) -> Self {
+        Self(val)
+    }
+
+    pub fn bits(&self) -> u64 {
+        self.0
+    }
+
+    pub fn is_enabled(&self, bit: u8) -> bool {
+        bit < 64 && (self.0 >> bit) & 0b1 == 1
+    }
+
+    /// Indices of enabled bits
+    pub fn enabled_bits(&self) -> impl Iterator<Item = u8> + '_ {
+        (0..64).filter(|bit| self.is_enabled(*bit))
+    }
+}
+
+/// A row of the feature table in `pp_features`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PpFeature {
+    /// e.g. "DS_GFXCLK", "FAN_CONTROL"
+    pub name: String,
+    pub bit: u8,
+    pub enabled: bool,
+}
+
+/// Parsed `pp_features`.  
+/// The feature names are taken from the table printed by the kernel for each SMU,
+/// if the table is missing or cannot be parsed, `features` is empty and only the mask is available.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PpFeatureList {
+    pub mask: PpFeatures,
+    pub features: Vec<PpFeature>,
+}
+
+impl PpFeatureList {
+    pub fn get_from_sysfs<P: Into<PathBuf>>(sysfs_path: P) -> Option<Self> {
+        let s = std::fs::read_to_string(sysfs_path.into().join("pp_features")).ok()?;
+
+        Self::parse(&s)
+    }
+
+    /*
+        SMU v11/v13/v14:
+            features high: 0x00000623 low: 0xb3cdaffb
+            No. Feature               Bit : State
+            00. FW_DATA_READ          ( 0) : enabled
+            01. DPM_GFXCLK            ( 1) : disabled
+
+        Vega10/12/20:
+            Current ppfeatures: 0x00000000000003ff
+            FEATURES            BITMASK            ENABLEMENT
+            DPM_PREFETCHER      0x0000000000000001  Y
+    */
+    pub fn parse(s: &str) -> Option<Self> {
+        let mut lines = s.lines();
+        let mask = Self::parse_mask(lines.next()?)?;
+        let features = lines.filter_map(|line| {
+            Self::parse_smu_line(line).or_else(|| Self::parse_vega_line(line))
+        }).collect();
+
+        Some(Self { mask, features })
+    }
+
+    /// Name of the feature bit, `None` if not in the table
+    pub fn name(&self, bit: u8) -> Option<&str> {
+        self.features.iter().find(|f| f.bit == bit).map(|f| f.name.as_str())
+    }
+
+    /// Names of enabled features, or `"bit N"` for bits not in the table
+    pub fn enabled_names(&self) -> Vec<String> {
+        self.mask.enabled_bits()
+            .map(|bit| self.name(bit).map_or_else(|| format!("bit {bit}"), |s| s.to_string()))
+            .collect()
+    }
+
+    pub fn is_enabled(&self, name: &str) -> Option<bool> {
+        self.features.iter().find(|f| f.name == name).map(|f| f.enabled)
+    }
+
+    fn parse_mask(line: &str) -> Option<PpFeatures> {
+        let hex = |s: &str| u64::from_str_radix(s.trim().trim_start_matches("0x"), 16).ok();
+
+        if let Some(s) = line.trim().strip_prefix("features high:") {
+            let (high, low) = s.split_once("low:")?;
+
+            return Some(PpFeatures((hex(high)? << 32) | hex(low)?));
+        }
+
+        let s = line.trim().strip_prefix("Current ppfeatures:")?;
+
+        Some(PpFeatures(hex(s)?))
+    }
+
+    fn parse_smu_line(line: &str) -> Option<PpFeature> {
+        let (_, rest) = line.split_once(". ")?;
+        let (name, rest) = rest.split_once('(')?;
+        let (bit, state) = rest.split_once(')')?;
+        let bit = bit.trim().parse::<u8>().ok()?;
+        let enabled = match state.trim_start_matches([' ', ':']).trim() {
+            "enabled" => true,
+            "disabled" => false,
+            _ => return None,
+        };
+
+        Some(PpFeature { name: name.trim().to_string(), bit, enabled })
+    }
+
+    fn parse_vega_line(line: &str) -> Option<PpFeature> {
+        let mut split = line.split_whitespace();
+        let [Some(name), Some(mask), Some(state)] = [split.next(), split.next(), split.next()] else {
+            return None;
+        };
+        let mask = u64::from_str_radix(mask.strip_prefix("0x")?, 16).ok()?;
+
+        if !mask.is_power_of_two() { return None }
+
+        let enabled = match state {
+            "Y" => true,
+            "N" => false,
+            _ => return None,
+        };
+
+        Some(PpFeature { name: name.to_string(), bit: mask.trailing_zeros() as u8, enabled })
+    }
+}
+
+impl DeviceHandle {
+    /// Enabled PowerPlay features mask from sysfs (`pp_features`)
+    pub fn get_pp_features(&self) -> Option<PpFeatures> {
+        self.get_pp_feature_list().map(|list| list.mask)
+    }
+
+    /// Enabled PowerPlay features with the names from sysfs (`pp_features`)
+    pub fn get_pp_feature_list(&self) -> Option<PpFeatureList> {
+        let sysfs_path = self.get_sysfs_path().ok()?;
+
+        PpFeatureList::get_from_sysfs(sysfs_path)
+    }
+}
+
+#[test]
+fn test_pp_features_parse() {
+    let smu = "features high: 0x00000001 low: 0x00000005\n\
+        No. Feature               Bit : State\n\
+        00. FW_DATA_READ          ( 0) : enabled\n\
+        01. DPM_GFXCLK            ( 1) : disabled\n\
+        02. DS_GFXCLK             ( 2) : enabled\n";
+    let list = PpFeatureList::parse(smu).unwrap();
+
+    assert_eq!(list.mask.bits(), 0x1_0000_0005);
+    assert_eq!(list.features.len(), 3);
+    assert_eq!(list.is_enabled("DS_GFXCLK"), Some(true));
+    assert_eq!(list.is_enabled("DPM_GFXCLK"), Some(false));
+    assert_eq!(list.enabled_names(), ["FW_DATA_READ", "DS_GFXCLK", "bit 32"]);
+
+    let vega = "Current ppfeatures: 0x0000000000000002\n\
+        FEATURES            BITMASK            ENABLEMENT\n\
+        DPM_PREFETCHER      0x0000000000000001  N\n\
+        GFXCLK_DPM          0x0000000000000002  Y\n";
+    let list = PpFeatureList::parse(vega).unwrap();
+
+    assert_eq!(list.mask.bits(), 0x2);
+    assert_eq!(list.name(1), Some("GFXCLK_DPM"));
+    assert_eq!(list.is_enabled("DPM_PREFETCHER"), Some(false));
+}
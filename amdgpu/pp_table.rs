@@ -1,6 +1,6 @@
 use core::mem::{size_of, MaybeUninit};
 use core::ptr;
-use crate::bindings::atom_common_table_header;
+pub use crate::bindings::atom_common_table_header;
 pub use crate::bindings::ppt::{
     smu_v11_0_0_ppt::{smu_11_0_powerplay_table, PPTable_t as PPTable_smu_11_0_t},
     smu_v11_0_7_ppt::{smu_11_0_7_powerplay_table, PPTable_t as PPTable_smu_11_0_7_t, PPTable_beige_goby_t},
@@ -43,6 +43,13 @@ impl PPTable {
         }
     }
 
+    /// Header of the PowerPlay table, enough to identify the format
+    /// (`format_revision`, `content_revision`) and the size (`structuresize`)
+    /// without decoding the table.
+    pub fn header(bytes: &[u8]) -> Option<atom_common_table_header> {
+        Self::get_header(bytes)
+    }
+
     fn check_length(header: &atom_common_table_header, len: usize) -> bool {
         header.structuresize as usize <= len
     }
@@ -124,3 +131,54 @@ impl PPTable {
         }
     }
 }
+
+#[cfg(feature = "std")]
+impl crate::AMDGPU::DeviceHandle {
+    /// Raw PowerPlay table from sysfs (`pp_table`)
+    pub fn get_pp_table(&self) -> std::io::Result<Vec<u8>> {
+        let sysfs_path = self.get_sysfs_path()
+            .map_err(|_| std::io::Error::from(std::io::ErrorKind::NotFound))?;
+
+        std::fs::read(sysfs_path.join("pp_table"))
+    }
+
+    /// Header of the PowerPlay table from sysfs (`pp_table`), see [PPTable::header]
+    pub fn get_pp_table_header(&self) -> Option<atom_common_table_header> {
+        PPTable::header(&self.get_pp_table().ok()?)
+    }
+
+    /// Upload a PowerPlay table to sysfs (`pp_table`). Requires root privileges.  
+    /// Rejects `bytes` if the size does not match `structuresize` of the header.
+    ///
+    /// **Danger**: The table is passed to the SMU firmware almost without validation.
+    /// A bad table (wrong format, out-of-spec clocks/voltages/power limits)
+    /// can hang the GPU, make the system unstable or damage the hardware.
+    /// The uploaded table is kept until the driver is reloaded or the system is rebooted.
+    pub fn set_pp_table(&self, bytes: &[u8]) -> std::io::Result<()> {
+        use std::io;
+
+        let header = PPTable::header(bytes)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "smaller than the header"))?;
+
+        if header.structuresize as usize != bytes.len() {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "size does not match the header"));
+        }
+
+        let sysfs_path = self.get_sysfs_path()
+            .map_err(|_| io::Error::from(io::ErrorKind::NotFound))?;
+
+        std::fs::write(sysfs_path.join("pp_table"), bytes)
+    }
+}
+
+#[test]
+fn test_pp_table_header() {
+    let bytes = [0x10, 0x00, 15, 0, 0xAA, 0xBB];
+    let header = PPTable::header(&bytes).unwrap();
+
+    let (size, format_rev, content_rev) =
+        (header.structuresize, header.format_revision, header.content_revision);
+
+    assert_eq!((size, format_rev, content_rev), (16, 15, 0));
+    assert!(PPTable::header(&bytes[..2]).is_none());
+}
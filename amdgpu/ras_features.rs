@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::fmt;
 use std::path::PathBuf;
 use std::io;
@@ -14,6 +15,20 @@ impl RasEnabledFeatures {
         (self.0 & ras as u64) != 0
     }
 
+    /// Whether the block supports RAS (upper 32 bits, `adev->ras_enabled << 32`),
+    /// [RasEnabledFeatures::is_supported] checks the features enabled now (lower 32 bits)
+    pub fn is_capable(&self, ras: RasBlock) -> bool {
+        (self.0 & ((ras as u64) << 32)) != 0
+    }
+
+    /// Whether ECC is enabled on VRAM (RAS feature of the UMC block),
+    /// `None` if the UMC block does not support RAS
+    pub fn vram_ecc_enabled(&self) -> Option<bool> {
+        if !self.is_capable(RasBlock::UMC) { return None }
+
+        Some(self.is_supported(RasBlock::UMC))
+    }
+
     pub fn mask_value(&self) -> u64 {
         self.0
     }
@@ -28,6 +43,12 @@ impl DeviceHandle {
 
         Ok(RasEnabledFeatures::new(v))
     }
+
+    /// Whether ECC is enabled on VRAM (RAS feature of the UMC block).  
+    /// `None` if the device does not support ECC (RAS of UMC), or RAS at all (the ioctl fails).
+    pub fn get_vram_ecc_enabled(&self) -> Option<bool> {
+        self.ras_enabled_features().ok()?.vram_ecc_enabled()
+    }
 }
 
 use crate::bindings::{
@@ -47,7 +68,7 @@ use crate::bindings::{
     AMDGPU_INFO_RAS_ENABLED_FUSE,
 };
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(u32)]
 pub enum RasBlock {
     UMC = AMDGPU_INFO_RAS_ENABLED_UMC,
@@ -67,6 +88,23 @@ pub enum RasBlock {
 }
 
 impl RasBlock {
+    pub const ALL: [Self; 14] = [
+        Self::UMC,
+        Self::SDMA,
+        Self::GFX,
+        Self::MMHUB,
+        Self::ATHUB,
+        Self::PCIE,
+        Self::HDP,
+        Self::XGMI,
+        Self::DF,
+        Self::SMN,
+        Self::SEM,
+        Self::MP0,
+        Self::MP1,
+        Self::FUSE,
+    ];
+
     /// ref: drivers/gpu/drm/amd/amdgpu/amdgpu_ras.c
     pub fn to_sysfs_name_prefix(&self) -> &str {
         match self {
@@ -94,7 +132,7 @@ impl fmt::Display for RasBlock {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct RasErrorCount {
     pub uncorrected: u64,
     pub corrected: u64,
@@ -112,17 +150,63 @@ impl RasErrorCount {
             std::fs::read_to_string(path)?
         };
 
-        let mut lines = s.lines();
+        Self::parse(&s).ok_or(io::Error::other("Parse Error"))
+    }
 
-        let [ue, ce] = [lines.next(), lines.next()].map(|line| -> io::Result<u64> {
-            const PRE: usize = "ue: ".len();
+    /*
+        "ue: 0"
+        "ce: 0"
+    */
+    pub fn parse(s: &str) -> Option<Self> {
+        let mut ue = None;
+        let mut ce = None;
+
+        for line in s.lines() {
+            let Some((name, val)) = line.split_once(':') else { continue };
+            let val = val.trim().parse::<u64>().ok();
+
+            match name.trim() {
+                "ue" => ue = val,
+                "ce" => ce = val,
+                _ => {},
+            }
+        }
 
-            line
-                .and_then(|l| l.get(PRE..))
-                .and_then(|s| s.parse().ok())
-                .ok_or(io::Error::other("Parse Error"))
-        });
+        Some(Self { uncorrected: ue?, corrected: ce? })
+    }
+}
 
-        Ok(Self { uncorrected: ue?, corrected: ce? })
+impl DeviceHandle {
+    /// Correctable/uncorrectable error counts of each RAS block from sysfs (`ras/*_err_count`).  
+    /// Returns an empty map if RAS is not supported.
+    pub fn get_ras_error_counts(&self) -> HashMap<RasBlock, RasErrorCount> {
+        let Ok(sysfs_path) = self.get_sysfs_path() else { return HashMap::new() };
+
+        RasBlock::ALL
+            .iter()
+            .filter_map(|block| {
+                let cnt = RasErrorCount::get_from_sysfs_with_ras_block(&sysfs_path, *block).ok()?;
+
+                Some((*block, cnt))
+            })
+            .collect()
     }
 }
+
+#[test]
+fn test_ras_error_count_parse() {
+    assert_eq!(
+        RasErrorCount::parse("ue: 1\nce: 23\n"),
+        Some(RasErrorCount { uncorrected: 1, corrected: 23 }),
+    );
+    assert_eq!(RasErrorCount::parse("ue: 0\n"), None);
+}
+
+#[test]
+fn test_vram_ecc_enabled() {
+    let umc = RasBlock::UMC as u64;
+
+    assert_eq!(RasEnabledFeatures::new(RasBlock::GFX as u64).vram_ecc_enabled(), None);
+    assert_eq!(RasEnabledFeatures::new(umc << 32).vram_ecc_enabled(), Some(false));
+    assert_eq!(RasEnabledFeatures::new((umc << 32) | umc).vram_ecc_enabled(), Some(true));
+}
@@ -3,11 +3,17 @@ use crate::*;
 use core::mem::{size_of, MaybeUninit};
 
 impl DeviceHandle {
+    /// Query an instantaneous value via `AMDGPU_INFO_SENSOR`, without sysfs.  
+    /// Every sensor returns a single DWORD (`u32`), see [SENSOR_TYPE] for the unit of each sensor.
+    pub fn query_sensor(&self, sensor_type: SENSOR_TYPE) -> Result<u32, i32> {
+        self.sensor_info(sensor_type)
+    }
+
     pub fn sensor_info(&self, sensor_type: SENSOR_TYPE) -> Result<u32, i32> {
         unsafe {
             let mut val: MaybeUninit<u32> = MaybeUninit::zeroed();
 
-            let r = bindings::amdgpu_query_sensor_info(
+            let r = ffi::amdgpu_query_sensor_info(
                 self.0,
                 sensor_type as u32,
                 size_of::<u32>() as u32,
@@ -37,21 +43,37 @@ use bindings::{
     AMDGPU_INFO_SENSOR_PEAK_PSTATE_GFX_SCLK,
 };
 
-/// Used for [DeviceHandle::sensor_info]
+/* ref: include/uapi/drm/amdgpu_drm.h, Linux Kernel 6.5 or later */
+const AMDGPU_INFO_SENSOR_GPU_INPUT_POWER: u32 = 0xC;
+
+/// Used for [DeviceHandle::query_sensor] and [DeviceHandle::sensor_info]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd)]
 #[repr(u32)]
 pub enum SENSOR_TYPE {
+    /// MHz
     GFX_SCLK = AMDGPU_INFO_SENSOR_GFX_SCLK,
+    /// MHz
     GFX_MCLK = AMDGPU_INFO_SENSOR_GFX_MCLK,
+    /// millidegrees Celsius
     GPU_TEMP = AMDGPU_INFO_SENSOR_GPU_TEMP,
+    /// %
     GPU_LOAD = AMDGPU_INFO_SENSOR_GPU_LOAD,
+    /// W
     GPU_AVG_POWER = AMDGPU_INFO_SENSOR_GPU_AVG_POWER,
+    /// mV
     VDDNB = AMDGPU_INFO_SENSOR_VDDNB,
+    /// mV
     VDDGFX = AMDGPU_INFO_SENSOR_VDDGFX,
+    /// MHz
     STABLE_PSTATE_GFX_SCLK = AMDGPU_INFO_SENSOR_STABLE_PSTATE_GFX_SCLK,
+    /// MHz
     STABLE_PSTATE_GFX_MCLK = AMDGPU_INFO_SENSOR_STABLE_PSTATE_GFX_MCLK,
+    /// MHz
     PEAK_PSTATE_GFX_SCLK = AMDGPU_INFO_SENSOR_PEAK_PSTATE_GFX_SCLK,
+    /// MHz
     PEAK_PSTATE_GFX_MCLK = AMDGPU_INFO_SENSOR_PEAK_PSTATE_GFX_MCLK,
+    /// W, Linux Kernel 6.5 or later
+    GPU_INPUT_POWER = AMDGPU_INFO_SENSOR_GPU_INPUT_POWER,
 }
 
 #[cfg(feature = "std")]
@@ -0,0 +1,140 @@
+use crate::AMDGPU::{DeviceHandle, SENSOR_INFO::SENSOR_TYPE};
+use std::path::PathBuf;
+
+/* ref: https://www.kernel.org/doc/html/latest/gpu/amdgpu/thermal.html */
+const FILE_NAMES: [&str; 8] = [
+    "freq1_input", // sclk, Hz
+    "freq2_input", // mclk, Hz
+    "temp1_input", // edge, millidegrees Celsius
+    "temp2_input", // junction, millidegrees Celsius
+    "temp3_input", // memory, millidegrees Celsius
+    "power1_average", // microWatts
+    "power1_input", // microWatts
+    "device/gpu_busy_percent", // %
+];
+
+/// A set of sensor values read from hwmon.
+/// Each value is `None` if the file is not supported or no longer available (e.g. GPU reset).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SensorSnapshot {
+    /// MHz
+    pub sclk: Option<u32>,
+    /// MHz
+    pub mclk: Option<u32>,
+    /// Celsius
+    pub edge_temp: Option<i64>,
+    /// Celsius
+    pub junction_temp: Option<i64>,
+    /// Celsius
+    pub memory_temp: Option<i64>,
+    /// W, `power1_average` or `power1_input`
+    pub power: Option<u32>,
+    /// %
+    pub gpu_load: Option<u32>,
+}
+
+impl SensorSnapshot {
+    pub fn from_hwmon_path<P: Into<PathBuf>>(path: P) -> Self {
+        let path = path.into();
+
+        Self::from_values(FILE_NAMES.map(|name| std::fs::read_to_string(path.join(name)).ok()))
+    }
+
+    /// Non-blocking version of [SensorSnapshot::from_hwmon_path]
+    #[cfg(feature = "tokio")]
+    pub async fn from_hwmon_path_async<P: Into<PathBuf>>(path: P) -> Self {
+        let path = path.into();
+        let mut values: [Option<String>; 8] = Default::default();
+
+        for (name, v) in FILE_NAMES.iter().zip(values.iter_mut()) {
+            *v = tokio::fs::read_to_string(path.join(name)).await.ok();
+        }
+
+        Self::from_values(values)
+    }
+
+    fn from_values(values: [Option<String>; 8]) -> Self {
+        let [sclk, mclk, edge, junction, memory, power_avg, power_input, load] = values.map(|s| {
+            s.and_then(|s| s.trim_end().parse::<i64>().ok())
+        });
+        let [sclk, mclk] = [sclk, mclk].map(|v| v.map(|hz| (hz / 1_000_000) as u32));
+        let [edge_temp, junction_temp, memory_temp] = [edge, junction, memory]
+            .map(|v| v.map(|t| t.saturating_div(1_000)));
+        let power = power_avg.or(power_input).map(|uw| (uw / 1_000_000) as u32);
+        let gpu_load = load.map(|v| v as u32);
+
+        Self { sclk, mclk, edge_temp, junction_temp, memory_temp, power, gpu_load }
+    }
+}
+
+impl DeviceHandle {
+    /// Query SCLK, MCLK, temperature, load and power via `AMDGPU_INFO_SENSOR` at once.  
+    /// The kernel does not guarantee atomicity across the queries,
+    /// each value is read by a separate ioctl.  
+    /// `junction_temp` and `memory_temp` are always `None`, use [SensorSnapshot::from_hwmon_path] for them.
+    pub fn get_sensor_snapshot(&self) -> SensorSnapshot {
+        let query = |sensor_type| self.query_sensor(sensor_type).ok();
+
+        SensorSnapshot {
+            sclk: query(SENSOR_TYPE::GFX_SCLK),
+            mclk: query(SENSOR_TYPE::GFX_MCLK),
+            edge_temp: query(SENSOR_TYPE::GPU_TEMP).map(|t| t as i64 / 1_000),
+            junction_temp: None,
+            memory_temp: None,
+            power: query(SENSOR_TYPE::GPU_AVG_POWER).or_else(|| query(SENSOR_TYPE::GPU_INPUT_POWER)),
+            gpu_load: query(SENSOR_TYPE::GPU_LOAD),
+        }
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl DeviceHandle {
+    /// Poll hwmon sensors every `interval` without blocking the async runtime.
+    /// Must be called within a Tokio runtime. The polling stops when the stream is dropped.  
+    /// `interval` is clamped to at least 10ms (`tokio::time::interval` panics on zero,
+    /// and hwmon values are not updated faster than that).
+    pub fn watch_sensors(
+        &self,
+        interval: std::time::Duration,
+    ) -> impl futures_util::Stream<Item = SensorSnapshot> {
+        const MIN_INTERVAL: std::time::Duration = std::time::Duration::from_millis(10);
+
+        let hwmon_path = self.get_hwmon_path();
+        let mut interval = tokio::time::interval(interval.max(MIN_INTERVAL));
+
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+        futures_util::stream::unfold((interval, hwmon_path), |(mut interval, path)| async move {
+            interval.tick().await;
+
+            let snapshot = match &path {
+                Some(path) => SensorSnapshot::from_hwmon_path_async(path).await,
+                None => SensorSnapshot::default(),
+            };
+
+            Some((snapshot, (interval, path)))
+        })
+    }
+}
+
+#[test]
+fn test_sensor_snapshot_from_values() {
+    let snapshot = SensorSnapshot::from_values([
+        Some("1800000000\n".to_string()),
+        Some("1000000000\n".to_string()),
+        Some("45000\n".to_string()),
+        None,
+        None,
+        None,
+        Some("25000000\n".to_string()),
+        Some("37\n".to_string()),
+    ]);
+
+    assert_eq!(snapshot.sclk, Some(1800));
+    assert_eq!(snapshot.mclk, Some(1000));
+    assert_eq!(snapshot.edge_temp, Some(45));
+    assert_eq!(snapshot.junction_temp, None);
+    assert_eq!(snapshot.power, Some(25));
+    assert_eq!(snapshot.gpu_load, Some(37));
+}
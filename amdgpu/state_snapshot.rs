@@ -0,0 +1,100 @@
+use crate::AMDGPU::{DeviceHandle, SENSOR_INFO::SENSOR_TYPE};
+use core::mem::size_of;
+
+/// Fixed-size snapshot of the GPU state for binary logging (e.g. ring buffer),
+/// without a serialization dependency.  
+/// All fields are 4 bytes with no padding, unavailable values are
+/// [StateSnapshot::UNAVAILABLE_U32] or [StateSnapshot::UNAVAILABLE_I32].  
+/// The byte order of [StateSnapshot::as_bytes] is native endian.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(C)]
+pub struct StateSnapshot {
+    /// MHz
+    pub sclk: u32,
+    /// MHz
+    pub mclk: u32,
+    /// Celsius
+    pub temp: i32,
+    /// W
+    pub power: u32,
+    /// %
+    pub gpu_load: u32,
+    /// MiB
+    pub vram_usage: u32,
+    /// MiB
+    pub gtt_usage: u32,
+}
+
+impl Default for StateSnapshot {
+    fn default() -> Self {
+        Self {
+            sclk: Self::UNAVAILABLE_U32,
+            mclk: Self::UNAVAILABLE_U32,
+            temp: Self::UNAVAILABLE_I32,
+            power: Self::UNAVAILABLE_U32,
+            gpu_load: Self::UNAVAILABLE_U32,
+            vram_usage: Self::UNAVAILABLE_U32,
+            gtt_usage: Self::UNAVAILABLE_U32,
+        }
+    }
+}
+
+impl StateSnapshot {
+    pub const UNAVAILABLE_U32: u32 = u32::MAX;
+    pub const UNAVAILABLE_I32: i32 = i32::MIN;
+    pub const SIZE: usize = size_of::<Self>();
+
+    pub fn as_bytes(&self) -> &[u8; Self::SIZE] {
+        // SAFETY: `#[repr(C)]` with only 4-byte integer fields, so there is no padding
+        unsafe { &*(self as *const Self as *const [u8; Self::SIZE]) }
+    }
+
+    /// Returns `None` if `bytes` is shorter than [StateSnapshot::SIZE]
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let bytes = bytes.get(..Self::SIZE)?;
+
+        // SAFETY: the length is checked, and every bit pattern is valid for the integer fields
+        Some(unsafe { core::ptr::read_unaligned(bytes.as_ptr() as *const Self) })
+    }
+}
+
+impl DeviceHandle {
+    /// Capture [StateSnapshot] via `AMDGPU_INFO_SENSOR` and `AMDGPU_INFO_*_USAGE`.  
+    /// Each value is read by a separate ioctl, the snapshot is not atomic.
+    pub fn capture_snapshot(&self) -> StateSnapshot {
+        const MIB: u64 = 1 << 20;
+        let sensor = |sensor_type| self.query_sensor(sensor_type).ok();
+        let mib = |usage: Result<u64, i32>| usage.ok().map(|v| (v / MIB) as u32);
+        let na = StateSnapshot::UNAVAILABLE_U32;
+
+        StateSnapshot {
+            sclk: sensor(SENSOR_TYPE::GFX_SCLK).unwrap_or(na),
+            mclk: sensor(SENSOR_TYPE::GFX_MCLK).unwrap_or(na),
+            temp: sensor(SENSOR_TYPE::GPU_TEMP)
+                .map_or(StateSnapshot::UNAVAILABLE_I32, |t| t as i32 / 1000),
+            power: sensor(SENSOR_TYPE::GPU_AVG_POWER)
+                .or_else(|| sensor(SENSOR_TYPE::GPU_INPUT_POWER))
+                .unwrap_or(na),
+            gpu_load: sensor(SENSOR_TYPE::GPU_LOAD).unwrap_or(na),
+            vram_usage: mib(self.vram_usage_info()).unwrap_or(na),
+            gtt_usage: mib(self.gtt_usage_info()).unwrap_or(na),
+        }
+    }
+}
+
+#[test]
+fn test_state_snapshot_bytes() {
+    let snapshot = StateSnapshot {
+        sclk: 2500,
+        temp: -5,
+        vram_usage: 4096,
+        ..Default::default()
+    };
+    let bytes = snapshot.as_bytes();
+
+    assert_eq!(StateSnapshot::SIZE, 28);
+    assert_eq!(&bytes[..4], &2500u32.to_ne_bytes());
+    assert_eq!(StateSnapshot::from_bytes(bytes), Some(snapshot));
+    assert_eq!(StateSnapshot::from_bytes(&bytes[1..]), None);
+    assert_eq!(StateSnapshot::default().power, StateSnapshot::UNAVAILABLE_U32);
+}
@@ -1,14 +1,39 @@
+use crate::AMDGPU::{DeviceHandle, MetricsInfo};
 #[cfg(feature = "std")]
 use std::fmt;
 
-#[derive(Debug, Clone, Copy)]
+/// ASIC-independent throttle status (`indep_throttle_status` of `gpu_metrics`).  
+/// The SMU driver translates the ASIC-specific `throttle_status` of each SMU version to the common
+/// bit mapping of `SMU_THROTTLER_*` (ref: drivers/gpu/drm/amd/pm/swsmu/inc/amdgpu_smu.h),
+/// which is used for decoding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ThrottleStatus(u64);
 
+impl DeviceHandle {
+    /// Decode the throttle status from `gpu_metrics`.  
+    /// Returns `None` if `gpu_metrics` does not have `indep_throttle_status`
+    /// (`gpu_metrics` v1.3 or later for dGPU, v2.2 or later for APU),
+    /// because the raw `throttle_status` has a different bit mapping for each SMU version.
+    pub fn get_throttle_status(&self) -> Option<ThrottleStatus> {
+        let metrics = self.get_gpu_metrics().ok()?;
+
+        metrics.get_throttle_status_info().filter(|thr| thr.0 != u64::MAX)
+    }
+}
+
 impl ThrottleStatus {
     pub fn new(val: u64) -> Self {
         Self(val)
     }
 
+    pub fn bits(&self) -> u64 {
+        self.0
+    }
+
+    pub fn is_throttling(&self) -> bool {
+        self.0 != 0
+    }
+
     pub fn check_throttler(&self, thr: ThrottlerBit) -> bool {
         ((self.0 >> thr as u64) & 0b1) == 1
     }
@@ -45,6 +70,20 @@ impl ThrottleStatus {
     }
 }
 
+/// List the active throttlers, e.g. "PPT0, TEMP_HOTSPOT"
+#[cfg(feature = "std")]
+impl fmt::Display for ThrottleStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if !self.is_throttling() {
+            return write!(f, "None");
+        }
+
+        let thrs: Vec<String> = self.get_all_throttler().iter().map(|thr| thr.to_string()).collect();
+
+        write!(f, "{}", thrs.join(", "))
+    }
+}
+
 /// ref: drivers/gpu/drm/amd/pm/swsmu/inc/amdgpu_smu.h
 struct SmuThrottler;
 
@@ -255,3 +294,12 @@ impl From<ThrottlerBit> for ThrottlerType {
         Self::from(&thr)
     }
 }
+
+#[test]
+fn test_throttle_status_display() {
+    let thr = ThrottleStatus::new((1 << SmuThrottler::PPT0) | (1 << SmuThrottler::TEMP_HOTSPOT));
+
+    assert!(thr.check_throttler(ThrottlerBit::TEMP_HOTSPOT));
+    assert_eq!(thr.to_string(), "PPT0, TEMP_HOTSPOT");
+    assert_eq!(ThrottleStatus::new(0).to_string(), "None");
+}
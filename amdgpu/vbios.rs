@@ -4,7 +4,8 @@ use crate::*;
 use core::mem::{size_of, MaybeUninit};
 use core::ptr;
 
-use bindings::{AMDGPU_INFO_VBIOS, DRM_AMDGPU_INFO, drmCommandWrite, drm_amdgpu_info};
+use bindings::{AMDGPU_INFO_VBIOS, DRM_AMDGPU_INFO, drm_amdgpu_info};
+use ffi::drmCommandWrite;
 
 /// VBIOS information
 #[cfg(feature = "std")]
@@ -18,6 +19,8 @@ pub struct VbiosInfo {
 }
 
 impl DeviceHandle {
+    /// Get VBIOS name/part number/version/date (`AMDGPU_INFO_VBIOS_INFO`).  
+    /// Non-UTF-8 bytes are replaced with `U+FFFD`.
     #[cfg(feature = "std")]
     pub fn get_vbios_info(&self) -> Result<VbiosInfo, i32> {
         let vbios = self.vbios_info()?;
@@ -30,11 +33,12 @@ impl DeviceHandle {
             vbios.date.to_vec(),
         ]
         .map(|v| {
-            if let Some(index) = v.iter().position(|&x| x == 0) {
-                String::from_utf8(v.get(..index).unwrap_or_default().to_vec())
-            } else {
-                String::from_utf8(v)
-            }.unwrap_or_default()
+            let v = match v.iter().position(|&x| x == 0) {
+                Some(index) => v.get(..index).unwrap_or_default(),
+                None => &v,
+            };
+
+            String::from_utf8_lossy(v).to_string()
         });
 
         Ok(VbiosInfo { name, pn, ver, date, size })
@@ -77,6 +81,7 @@ impl DeviceHandle {
         unsafe { Self::query_vbios(self, AMDGPU_INFO_VBIOS_INFO) }
     }
 
+    /// VBIOS image size in bytes (`AMDGPU_INFO_VBIOS_SIZE`)
     pub fn vbios_size(&self) -> Result<u32, i32> {
         use bindings::AMDGPU_INFO_VBIOS_SIZE;
 
@@ -116,6 +121,7 @@ impl DeviceHandle {
     }
 
 
+    /// Dump the raw VBIOS ROM (`AMDGPU_INFO_VBIOS_IMAGE`)
     #[cfg(feature = "std")]
     pub fn get_vbios_image(&self) -> Result<Vec<u8>, i32> {
         let size = self.vbios_size()?;
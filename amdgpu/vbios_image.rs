@@ -0,0 +1,93 @@
+use crate::AMDGPU::DeviceHandle;
+
+/// Signature of the PCI expansion ROM (`0x55, 0xAA`)
+pub const ROM_SIGNATURE: u16 = 0xAA55;
+
+/// VBIOS ROM image with the checks of the legacy PCI expansion ROM header,
+/// for validating a dump before writing/flashing it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VbiosImage(Vec<u8>);
+
+impl VbiosImage {
+    pub fn new(v: Vec<u8>) -> Self {
+        Self(v)
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> Vec<u8> {
+        self.0
+    }
+
+    /// ROM signature (offset `0x0`, little-endian), [ROM_SIGNATURE] for a valid image
+    pub fn signature(&self) -> Option<u16> {
+        let sig = self.0.get(..2)?;
+
+        Some(u16::from_le_bytes([sig[0], sig[1]]))
+    }
+
+    pub fn has_valid_signature(&self) -> bool {
+        self.signature() == Some(ROM_SIGNATURE)
+    }
+
+    /// ROM size in the header (offset `0x2`, in units of 512 bytes), Byte
+    pub fn rom_size(&self) -> usize {
+        self.0.get(2).map_or(0, |size| usize::from(*size) << 9)
+    }
+
+    /// Legacy checksum: the bytes in the ROM size of the header sum to zero (mod 256).
+    /// `false` if the image is smaller than the ROM size.
+    pub fn checksum_ok(&self) -> bool {
+        let size = self.rom_size();
+
+        size != 0 && self.0.get(..size).is_some_and(|rom| {
+            rom.iter().fold(0u8, |sum, b| sum.wrapping_add(*b)) == 0
+        })
+    }
+
+    /// Both [VbiosImage::has_valid_signature] and [VbiosImage::checksum_ok]
+    pub fn is_valid(&self) -> bool {
+        self.has_valid_signature() && self.checksum_ok()
+    }
+}
+
+impl From<Vec<u8>> for VbiosImage {
+    fn from(v: Vec<u8>) -> Self {
+        Self(v)
+    }
+}
+
+impl DeviceHandle {
+    /// [DeviceHandle::get_vbios_image] as [VbiosImage]
+    pub fn get_vbios_rom(&self) -> Result<VbiosImage, i32> {
+        self.get_vbios_image().map(VbiosImage)
+    }
+}
+
+#[test]
+fn test_vbios_image_checksum() {
+    let mut rom = vec![0u8; 1024];
+    rom[0] = 0x55;
+    rom[1] = 0xAA;
+    rom[2] = 2; // 1024 bytes
+    rom[0x10] = 0x12;
+
+    let sum = rom.iter().fold(0u8, |sum, b| sum.wrapping_add(*b));
+    rom[0x21] = sum.wrapping_neg();
+
+    let image = VbiosImage::new(rom.clone());
+
+    assert_eq!(image.signature(), Some(ROM_SIGNATURE));
+    assert_eq!(image.rom_size(), 1024);
+    assert!(image.is_valid());
+
+    rom[0x10] = 0x13;
+    assert!(!VbiosImage::new(rom.clone()).checksum_ok());
+
+    rom[2] = 4; // larger than the image
+    assert!(!VbiosImage::new(rom).checksum_ok());
+
+    assert!(!VbiosImage::new(vec![0xAA, 0x55, 1]).has_valid_signature());
+}
@@ -0,0 +1,76 @@
+use crate::AMDGPU::DeviceHandle;
+use crate::bindings::{
+    AMDGPU_VCE_CLOCK_TABLE_ENTRIES,
+    drm_amdgpu_info_vce_clock_table,
+    drm_amdgpu_info_vce_clock_table_entry,
+};
+
+/// 10 GHz in 10 KHz
+const MAX_PLAUSIBLE_CLOCK: u32 = 1_000_000;
+
+/// A VCE clock state, in 10 KHz units as stored in the powerplay table
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VceClockEntry {
+    pub sclk: u32,
+    pub mclk: u32,
+    /// `evclk` of the VCE state
+    pub eclk: u32,
+}
+
+/// Validated [drm_amdgpu_info_vce_clock_table]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VceClockTable {
+    pub entries: Vec<VceClockEntry>,
+}
+
+impl VceClockEntry {
+    fn from_raw(entry: &drm_amdgpu_info_vce_clock_table_entry) -> Option<Self> {
+        let plausible = |clk: u32| (1..=MAX_PLAUSIBLE_CLOCK).contains(&clk);
+
+        if !(plausible(entry.sclk) && plausible(entry.mclk) && plausible(entry.eclk)) {
+            return None;
+        }
+
+        Some(Self { sclk: entry.sclk, mclk: entry.mclk, eclk: entry.eclk })
+    }
+}
+
+impl VceClockTable {
+    /// Returns `Err(-EINVAL)` if `num_valid_entries` exceeds the array bound.  
+    /// The kernel fills the entries by VCE state index, so the valid entries are not always
+    /// at the front of the array. All entries are checked, and entries with zero or
+    /// implausible clocks are dropped.
+    pub fn from_raw(table: &drm_amdgpu_info_vce_clock_table) -> Result<Self, i32> {
+        if table.num_valid_entries > AMDGPU_VCE_CLOCK_TABLE_ENTRIES {
+            return Err(-libc::EINVAL);
+        }
+
+        let entries = table.entries.iter().filter_map(VceClockEntry::from_raw).collect();
+
+        Ok(Self { entries })
+    }
+}
+
+impl DeviceHandle {
+    /// [DeviceHandle::vce_clock_info] with validation, see [VceClockTable::from_raw]
+    pub fn vce_clock_info_checked(&self) -> Result<VceClockTable, i32> {
+        VceClockTable::from_raw(&self.vce_clock_info()?)
+    }
+}
+
+#[test]
+fn test_vce_clock_table_from_raw() {
+    let mut table: drm_amdgpu_info_vce_clock_table = unsafe { core::mem::zeroed() };
+
+    table.entries[1] = drm_amdgpu_info_vce_clock_table_entry { sclk: 80000, mclk: 125000, eclk: 60000, pad: 0 };
+    table.entries[2] = drm_amdgpu_info_vce_clock_table_entry { sclk: u32::MAX, mclk: 125000, eclk: 60000, pad: 0 };
+    table.num_valid_entries = 2;
+
+    let checked = VceClockTable::from_raw(&table).unwrap();
+
+    assert_eq!(checked.entries, [VceClockEntry { sclk: 80000, mclk: 125000, eclk: 60000 }]);
+
+    table.num_valid_entries = 7;
+
+    assert_eq!(VceClockTable::from_raw(&table), Err(-libc::EINVAL));
+}
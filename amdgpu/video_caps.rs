@@ -46,6 +46,34 @@ impl DeviceHandle {
 
         Ok(VideoCapsInfo::from((&cap_type, &cap)))
     }
+
+    /// Returns only the supported codecs.
+    /// APUs and older dGPUs report unsupported codecs as invalid (zero), those are filtered.
+    #[cfg(feature = "std")]
+    pub fn query_video_caps(&self, cap_type: CAP_TYPE) -> Result<Vec<VideoCodecCap>, i32> {
+        Ok(self.get_video_caps(cap_type)?.supported_codecs())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VideoCodecCap {
+    pub codec: CODEC,
+    pub max_width: u32,
+    pub max_height: u32,
+    pub max_pixels_per_frame: u32,
+    pub max_level: u32,
+}
+
+impl VideoCodecCap {
+    pub fn from_codec_info(codec: CODEC, info: &drm_amdgpu_info_video_codec_info) -> Self {
+        Self {
+            codec,
+            max_width: info.max_width,
+            max_height: info.max_height,
+            max_pixels_per_frame: info.max_pixels_per_frame,
+            max_level: info.max_level,
+        }
+    }
 }
 
 use bindings::{
@@ -66,7 +94,7 @@ impl DeviceHandle {
         unsafe {
             let mut video_caps: MaybeUninit<drm_amdgpu_info_video_caps> = MaybeUninit::zeroed();
 
-            let r = bindings::amdgpu_query_video_caps_info(
+            let r = ffi::amdgpu_query_video_caps_info(
                 self.0,
                 type_ as u32,
                 size_of::<drm_amdgpu_info_video_caps>() as u32,
@@ -86,6 +114,15 @@ impl drm_amdgpu_info_video_caps {
     pub fn get_codec_info(&self, codec: CODEC) -> drm_amdgpu_info_video_codec_info {
         self.codec_info[codec as usize]
     }
+
+    #[cfg(feature = "std")]
+    pub fn supported_codecs(&self) -> Vec<VideoCodecCap> {
+        CODEC::LIST.iter().filter_map(|codec| {
+            let info = self.get_codec_info(*codec);
+
+            info.is_supported().then(|| VideoCodecCap::from_codec_info(*codec, &info))
+        }).collect()
+    }
 }
 
 use bindings::{
@@ -141,3 +178,22 @@ impl drm_amdgpu_info_video_codec_info {
         self.valid != 0
     }
 }
+
+#[cfg(feature = "std")]
+#[test]
+fn test_supported_codecs() {
+    let mut caps: drm_amdgpu_info_video_caps = unsafe { core::mem::zeroed() };
+    let hevc = &mut caps.codec_info[CODEC::HEVC as usize];
+
+    hevc.valid = 1;
+    hevc.max_width = 4096;
+    hevc.max_height = 2304;
+    hevc.max_level = 186;
+
+    let codecs = caps.supported_codecs();
+
+    assert_eq!(codecs.len(), 1);
+    assert_eq!(codecs[0].codec, CODEC::HEVC);
+    assert_eq!((codecs[0].max_width, codecs[0].max_height), (4096, 2304));
+    assert_eq!(codecs[0].max_level, 186);
+}
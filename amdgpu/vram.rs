@@ -16,9 +16,11 @@ use bindings::{
 
 const AMDGPU_VRAM_TYPE_LPDDR4: u32 = 11;
 const AMDGPU_VRAM_TYPE_LPDDR5: u32 = 12;
+const AMDGPU_VRAM_TYPE_HBM3E: u32 = 13;
 
-/// List of AMDGPU VRAM types
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd)]
+/// List of AMDGPU VRAM types, get it from `drm_amdgpu_info_device` with [AMDGPU::GPU_INFO::get_vram_type].  
+/// The kernel reports HBM, HBM2 and HBM3 as the same [VRAM_TYPE::HBM].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Hash)]
 #[repr(u32)]
 pub enum VRAM_TYPE {
     GDDR1 = AMDGPU_VRAM_TYPE_GDDR1,
@@ -33,6 +35,7 @@ pub enum VRAM_TYPE {
     DDR5 = AMDGPU_VRAM_TYPE_DDR5,
     LPDDR4 = AMDGPU_VRAM_TYPE_LPDDR4,
     LPDDR5 = AMDGPU_VRAM_TYPE_LPDDR5,
+    HBM3E = AMDGPU_VRAM_TYPE_HBM3E,
     UNKNOWN = AMDGPU_VRAM_TYPE_UNKNOWN,
 }
 
@@ -51,12 +54,37 @@ impl From<u32> for VRAM_TYPE {
             AMDGPU_VRAM_TYPE_DDR5 => Self::DDR5,
             AMDGPU_VRAM_TYPE_LPDDR4 => Self::LPDDR4,
             AMDGPU_VRAM_TYPE_LPDDR5 => Self::LPDDR5,
+            AMDGPU_VRAM_TYPE_HBM3E => Self::HBM3E,
             AMDGPU_VRAM_TYPE_UNKNOWN | _ => Self::UNKNOWN,
         }
     }
 }
 
 impl VRAM_TYPE {
+    /// `AMDGPU_VRAM_TYPE_HBM` is used for HBM, HBM2 and HBM3
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::GDDR1 => "GDDR1",
+            Self::DDR2 => "DDR2",
+            Self::GDDR3 => "GDDR3",
+            Self::GDDR4 => "GDDR4",
+            Self::GDDR5 => "GDDR5",
+            Self::HBM => "HBM",
+            Self::DDR3 => "DDR3",
+            Self::DDR4 => "DDR4",
+            Self::GDDR6 => "GDDR6",
+            Self::DDR5 => "DDR5",
+            Self::LPDDR4 => "LPDDR4",
+            Self::LPDDR5 => "LPDDR5",
+            Self::HBM3E => "HBM3E",
+            Self::UNKNOWN => "UNKNOWN",
+        }
+    }
+
+    pub fn is_hbm(&self) -> bool {
+        matches!(self, Self::HBM | Self::HBM3E)
+    }
+
     /* https://www.kernel.org/doc/html/latest/gpu/amdgpu/thermal.html#pp-od-clk-voltage */
     /*
     fn clk_rate(&self) -> u64 {
@@ -90,7 +118,8 @@ impl VRAM_TYPE {
             */
             Self::LPDDR4 |
             Self::LPDDR5 => 64,
-            Self::HBM => 128,
+            Self::HBM |
+            Self::HBM3E => 128,
             Self::GDDR1 |
             Self::GDDR3 |
             Self::GDDR4 |
@@ -100,9 +129,16 @@ impl VRAM_TYPE {
         }
     }
 
-    /// Memory ops per clock  
-    /// ref: https://github.com/GPUOpen-Drivers/pal/blob/dev/src/core/device.cpp
-    fn memory_ops_per_clock(&self) -> u64 {
+    /// Memory ops (transfers) per memory clock reported by the driver  
+    /// ref: <https://github.com/GPUOpen-Drivers/pal/blob/dev/src/core/device.cpp>
+    ///
+    /// | VRAM type                                  | ops/clock |
+    /// |--------------------------------------------|-----------|
+    /// | DDR2, DDR3, DDR4, DDR5, HBM, HBM3E, LPDDR4 | 2         |
+    /// | GDDR5, LPDDR5                              | 4         |
+    /// | GDDR6                                      | 16        |
+    /// | others                                     | 1         |
+    pub fn memory_ops_per_clock(&self) -> u64 {
         match self {
             Self::DDR2 |
             Self::DDR3 |
@@ -112,6 +148,7 @@ impl VRAM_TYPE {
             */
             Self::DDR5 |
             Self::HBM |
+            Self::HBM3E |
             Self::LPDDR4 => 2,
             Self::GDDR5 |
             // Self::DDR5 |
@@ -121,6 +158,13 @@ impl VRAM_TYPE {
         }
     }
 
+    /// Effective data rate (MT/s) of the memory clock (MHz) reported by the driver,
+    /// using [VRAM_TYPE::memory_ops_per_clock].  
+    /// e.g. GDDR6, 1000 MHz: 16000 MT/s (16 Gbps per pin)
+    pub fn effective_data_rate(&self, mem_clk_mhz: u32) -> u32 {
+        (mem_clk_mhz as u64 * self.memory_ops_per_clock()).min(u32::MAX as u64) as u32
+    }
+
     /// Peak Memory Bandwidth (MB/s)
     pub fn peak_bw(&self, max_mem_clk_khz: u64, vram_bit_width: u32) -> u64 {
         let eff_mem_clk_mhz = (max_mem_clk_khz / 1000) * self.memory_ops_per_clock();
@@ -135,10 +179,63 @@ impl VRAM_TYPE {
 }
 
 #[cfg(feature = "std")]
-use std::fmt;
-#[cfg(feature = "std")]
+impl AMDGPU::DeviceHandle {
+    /// Peak memory bandwidth (Byte/s) from the max memory clock of DPM (`pp_dpm_mclk`),
+    /// VRAM bit width and [VRAM_TYPE::memory_ops_per_clock].  
+    /// `max mclk (MHz) * ops/clock * bit width / 8`, e.g. GDDR6, 1000 MHz, 256-bit: 512 GB/s  
+    /// Returns `None` if any of the inputs is not available.
+    pub fn estimate_memory_bandwidth(&self) -> Option<u64> {
+        use AMDGPU::GPU_INFO;
+
+        let info = self.device_info().ok()?;
+        let vram_type = info.get_vram_type();
+        let (_, max_mclk_mhz) = self.get_min_max_memory_clock()?;
+
+        if vram_type == VRAM_TYPE::UNKNOWN || info.vram_bit_width() == 0 { return None }
+
+        Some(vram_type.peak_bw(max_mclk_mhz as u64 * 1000, info.vram_bit_width()) * 1_000_000)
+    }
+
+    /// Current memory clock (MHz) and its effective data rate (MT/s),
+    /// see [VRAM_TYPE::effective_data_rate].  
+    /// Returns `None` if the VRAM type is unknown or the memory clock is not available.
+    pub fn get_memory_clock_effective(&self) -> Option<(u32, u32)> {
+        use AMDGPU::GPU_INFO;
+
+        let vram_type = self.device_info().ok()?.get_vram_type();
+
+        if vram_type == VRAM_TYPE::UNKNOWN { return None }
+
+        let mem_clk_mhz = self.get_current_clocks().mem?;
+
+        Some((mem_clk_mhz, vram_type.effective_data_rate(mem_clk_mhz)))
+    }
+}
+
+use core::fmt;
 impl fmt::Display for VRAM_TYPE {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{:?}", self)
+        write!(f, "{}", self.as_str())
     }
 }
+
+#[test]
+fn test_vram_type_str() {
+    assert_eq!(VRAM_TYPE::from(AMDGPU_VRAM_TYPE_GDDR6).as_str(), "GDDR6");
+    assert_eq!(VRAM_TYPE::from(13).as_str(), "HBM3E");
+    assert_eq!(VRAM_TYPE::from(0xFF), VRAM_TYPE::UNKNOWN);
+    assert!(VRAM_TYPE::HBM3E.is_hbm());
+}
+
+#[test]
+fn test_vram_peak_bw() {
+    /* RX 6900 XT: GDDR6, 1000 MHz, 256-bit */
+    assert_eq!(VRAM_TYPE::GDDR6.peak_bw(1_000_000, 256), 512_000);
+    /* RX 580: GDDR5, 2000 MHz, 256-bit */
+    assert_eq!(VRAM_TYPE::GDDR5.peak_bw(2_000_000, 256), 256_000);
+    /* Radeon VII: HBM2, 1000 MHz, 4096-bit */
+    assert_eq!(VRAM_TYPE::HBM.peak_bw_gb(1_000_000, 4096), 1024);
+
+    assert_eq!(VRAM_TYPE::GDDR6.effective_data_rate(1000), 16000);
+    assert_eq!(VRAM_TYPE::HBM.effective_data_rate(1000), 2000);
+}
@@ -0,0 +1,139 @@
+use crate::AMDGPU::DeviceHandle;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WaitError {
+    /// The GPU did not stay idle for the stable window within the timeout
+    Timeout,
+    /// Neither `gpu_busy_percent` nor `GRBM_STATUS` can be read
+    Unsupported,
+}
+
+impl std::fmt::Display for WaitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Timeout => write!(f, "Timed out waiting for the GPU to be idle"),
+            Self::Unsupported => write!(f, "GPU activity is not available"),
+        }
+    }
+}
+
+impl std::error::Error for WaitError {}
+
+/// Builder for [DeviceHandle::wait_for_idle] with custom parameters.
+///
+/// ```ignore
+/// IdleWaiter::new()
+///     .threshold(10)
+///     .stable_window(Duration::from_secs(1))
+///     .wait(&amdgpu_dev, Duration::from_secs(10))?;
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IdleWaiter {
+    threshold: u8,
+    stable_window: Duration,
+    poll_interval: Duration,
+}
+
+impl Default for IdleWaiter {
+    fn default() -> Self {
+        Self {
+            threshold: 5,
+            stable_window: Duration::from_millis(500),
+            poll_interval: Duration::from_millis(50),
+        }
+    }
+}
+
+impl IdleWaiter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The GPU is considered idle while the activity is below `percent` (default: 5%)
+    pub fn threshold(mut self, percent: u8) -> Self {
+        self.threshold = percent;
+        self
+    }
+
+    /// How long the GPU must stay idle (default: 500ms)
+    pub fn stable_window(mut self, window: Duration) -> Self {
+        self.stable_window = window;
+        self
+    }
+
+    /// Interval between samples (default: 50ms)
+    pub fn poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// Block until the GPU activity stays below the threshold for the stable window,
+    /// or `timeout` elapses.
+    /// The activity is `gpu_busy_percent` in sysfs, or `GRBM_STATUS.GUI_ACTIVE`
+    /// (`100` if set, `0` otherwise) if the node is missing.
+    pub fn wait(&self, amdgpu_dev: &DeviceHandle, timeout: Duration) -> Result<(), WaitError> {
+        let sysfs_path = amdgpu_dev.get_sysfs_path().ok();
+
+        self.wait_with(timeout, || {
+            sysfs_path.as_ref()
+                .and_then(|path| DeviceHandle::get_busy_percent_from_sysfs(path.join("gpu_busy_percent")))
+                .or_else(|| {
+                    let grbm = amdgpu_dev.read_grbm_status().ok()?;
+
+                    Some(if grbm.gui_active() { 100 } else { 0 })
+                })
+        })
+    }
+
+    fn wait_with<F: FnMut() -> Option<u8>>(
+        &self,
+        timeout: Duration,
+        mut sample: F,
+    ) -> Result<(), WaitError> {
+        let start = Instant::now();
+        let mut idle_since: Option<Instant> = None;
+
+        loop {
+            let busy = sample().ok_or(WaitError::Unsupported)?;
+            let now = Instant::now();
+
+            if busy < self.threshold {
+                let since = *idle_since.get_or_insert(now);
+
+                if now.duration_since(since) >= self.stable_window {
+                    return Ok(());
+                }
+            } else {
+                idle_since = None;
+            }
+
+            if now.duration_since(start) >= timeout {
+                return Err(WaitError::Timeout);
+            }
+
+            std::thread::sleep(self.poll_interval);
+        }
+    }
+}
+
+impl DeviceHandle {
+    /// Block until the GPU is idle, with the default parameters of [IdleWaiter]
+    /// (below 5% for 500ms, sampled every 50ms).
+    pub fn wait_for_idle(&self, timeout: Duration) -> Result<(), WaitError> {
+        IdleWaiter::default().wait(self, timeout)
+    }
+}
+
+#[test]
+fn test_idle_waiter() {
+    let waiter = IdleWaiter::new()
+        .threshold(10)
+        .stable_window(Duration::ZERO)
+        .poll_interval(Duration::ZERO);
+    let mut samples = [80, 50, 3].into_iter();
+
+    assert_eq!(waiter.wait_with(Duration::from_secs(1), || samples.next()), Ok(()));
+    assert_eq!(waiter.wait_with(Duration::ZERO, || Some(90)), Err(WaitError::Timeout));
+    assert_eq!(waiter.wait_with(Duration::from_secs(1), || None), Err(WaitError::Unsupported));
+}
@@ -0,0 +1,89 @@
+use crate::AMDGPU::DeviceHandle;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+/// XGMI hive membership from sysfs (`xgmi_device_id`, `xgmi_physical_id`, `xgmi_hive_info/`)
+/// ref: drivers/gpu/drm/amd/amdgpu/amdgpu_xgmi.c
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct XgmiInfo {
+    /// `xgmi_hive_info/xgmi_hive_id`
+    pub hive_id: u64,
+    /// `xgmi_device_id`
+    pub device_id: u64,
+    /// `xgmi_physical_id`
+    pub physical_id: u32,
+    /// Number of `xgmi_hive_info/node#`
+    pub num_nodes: u32,
+}
+
+impl DeviceHandle {
+    /// Returns `None` on non-XGMI cards
+    pub fn get_xgmi_info(&self) -> Option<XgmiInfo> {
+        let sysfs_path = self.get_sysfs_path().ok()?;
+
+        XgmiInfo::from_sysfs_path(sysfs_path)
+    }
+}
+
+impl XgmiInfo {
+    pub fn from_sysfs_path<P: Into<PathBuf>>(sysfs_path: P) -> Option<Self> {
+        let path = sysfs_path.into();
+        let hive_path = path.join("xgmi_hive_info");
+        let [hive_id, device_id, physical_id] = [
+            hive_path.join("xgmi_hive_id"),
+            path.join("xgmi_device_id"),
+            path.join("xgmi_physical_id"),
+        ].map(|path| std::fs::read_to_string(path).ok());
+        let entries: Vec<String> = std::fs::read_dir(&hive_path).ok()?
+            .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+            .collect();
+
+        Self::parse(&hive_id?, &device_id?, &physical_id?, entries.iter().map(|s| s.as_str()))
+    }
+
+    /// Parse the contents of `xgmi_hive_info/xgmi_hive_id`, `xgmi_device_id`, `xgmi_physical_id`,
+    /// and the entry names of `xgmi_hive_info/`
+    pub fn parse<'a, I: IntoIterator<Item = &'a str>>(
+        hive_id: &str,
+        device_id: &str,
+        physical_id: &str,
+        hive_entries: I,
+    ) -> Option<Self> {
+        let hive_id = hive_id.trim_end().parse().ok()?;
+        let device_id = device_id.trim_end().parse().ok()?;
+        let physical_id = physical_id.trim_end().parse().ok()?;
+        let num_nodes = hive_entries
+            .into_iter()
+            .filter(|name| name.strip_prefix("node").is_some_and(|n| n.parse::<u32>().is_ok()))
+            .count() as u32;
+
+        Some(Self { hive_id, device_id, physical_id, num_nodes })
+    }
+
+    /// Group devices by XGMI hive id, devices without XGMI are excluded
+    pub fn group_by_hive(devs: &[DeviceHandle]) -> BTreeMap<u64, Vec<&DeviceHandle>> {
+        let mut map: BTreeMap<u64, Vec<&DeviceHandle>> = BTreeMap::new();
+
+        for dev in devs {
+            let Some(xgmi) = dev.get_xgmi_info() else { continue };
+
+            map.entry(xgmi.hive_id).or_default().push(dev);
+        }
+
+        map
+    }
+}
+
+#[test]
+fn test_xgmi_info_parse() {
+    let entries = ["xgmi_hive_id", "node1", "node2", "node3", "node4", "power"];
+    let info = XgmiInfo::parse("1234567890\n", "9876543210\n", "2\n", entries);
+
+    assert_eq!(info, Some(XgmiInfo {
+        hive_id: 1234567890,
+        device_id: 9876543210,
+        physical_id: 2,
+        num_nodes: 4,
+    }));
+    assert_eq!(XgmiInfo::parse("", "9876543210\n", "2\n", entries), None);
+}
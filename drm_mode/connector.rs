@@ -1,4 +1,4 @@
-use crate::{bindings, query_error};
+use crate::{bindings, ffi, query_error};
 use crate::drmModeObjectProperties;
 use core::ptr::addr_of;
 pub use bindings::{drmModeConnectorPtr, drmModeModeInfo};
@@ -8,7 +8,7 @@ pub struct drmModeConnector(pub(crate) drmModeConnectorPtr);
 
 impl drmModeConnector {
     pub fn get(fd: i32, connector_id: u32) -> Option<Self> {
-        let c_ptr = unsafe { bindings::drmModeGetConnector(fd, connector_id) };
+        let c_ptr = unsafe { ffi::drmModeGetConnector(fd, connector_id) };
 
         if c_ptr.is_null() { return None; }
 
@@ -16,7 +16,7 @@ impl drmModeConnector {
     }
 
     pub fn get_current(fd: i32, connector_id: u32) -> Option<Self> {
-        let c_ptr = unsafe { bindings::drmModeGetConnectorCurrent(fd, connector_id) };
+        let c_ptr = unsafe { ffi::drmModeGetConnectorCurrent(fd, connector_id) };
 
         if c_ptr.is_null() { return None; }
 
@@ -30,7 +30,7 @@ impl drmModeConnector {
         value: u64,
     ) -> Result<(), i32> {
         let r = unsafe {
-            bindings::drmModeConnectorSetProperty(fd, connector_id, property_id, value)
+            ffi::drmModeConnectorSetProperty(fd, connector_id, property_id, value)
         };
 
         query_error!(r);
@@ -88,7 +88,7 @@ impl drmModeConnector {
 
 impl Drop for drmModeConnector {
     fn drop(&mut self) {
-	    unsafe { bindings::drmModeFreeConnector(self.0); }
+	    unsafe { ffi::drmModeFreeConnector(self.0); }
     }
 }
 
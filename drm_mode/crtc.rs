@@ -1,4 +1,4 @@
-use crate::bindings;
+use crate::{bindings, ffi};
 use crate::drmModeObjectProperties;
 use bindings::drmModeCrtcPtr;
 pub use bindings::drmModeCrtc;
@@ -7,13 +7,13 @@ struct WrapperDrmModeCrtcPtr(pub(crate) drmModeCrtcPtr);
 
 impl Drop for WrapperDrmModeCrtcPtr {
     fn drop(&mut self) {
-	    unsafe { bindings::drmModeFreeCrtc(self.0); }
+	    unsafe { ffi::drmModeFreeCrtc(self.0); }
     }
 }
 
 impl drmModeCrtc {
     pub fn get(fd: i32, crtc_id: u32) -> Option<Self> {
-        let ptr = unsafe { bindings::drmModeGetCrtc(fd, crtc_id) };
+        let ptr = unsafe { ffi::drmModeGetCrtc(fd, crtc_id) };
 
         if ptr.is_null() { return None; }
 
@@ -32,7 +32,7 @@ pub(crate) fn c_char_to_string(c: &[core::ffi::c_char]) -> String {
     }.to_string()
 }
 
-use crate::bindings;
+use crate::ffi;
 
 pub use crate::bindings::{
     DRM_CLIENT_CAP_STEREO_3D,
@@ -44,7 +44,7 @@ pub use crate::bindings::{
 };
 
 pub fn set_client_caps(fd: i32, cap: u64, val: u64) -> i32 {
-    unsafe { bindings::drmSetClientCap(fd, cap, val) }
+    unsafe { ffi::drmSetClientCap(fd, cap, val) }
 }
 
 pub fn set_all_client_caps(fd: i32) {
@@ -56,6 +56,6 @@ pub fn set_all_client_caps(fd: i32) {
         DRM_CLIENT_CAP_WRITEBACK_CONNECTORS,
         DRM_CLIENT_CAP_CURSOR_PLANE_HOTSPOT,
     ] {
-        let _ = unsafe { bindings::drmSetClientCap(fd, cap as u64, 1) };
+        let _ = unsafe { ffi::drmSetClientCap(fd, cap as u64, 1) };
     }
 }
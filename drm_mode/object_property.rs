@@ -1,4 +1,4 @@
-use crate::{bindings, query_error};
+use crate::{bindings, ffi, query_error};
 use core::ptr::addr_of;
 
 pub use bindings::drmModeObjectPropertiesPtr;
@@ -9,7 +9,7 @@ pub struct drmModeObjectProperties(pub(crate) drmModeObjectPropertiesPtr);
 
 impl drmModeObjectProperties {
     pub fn get(fd: i32, object_id: u32, object_type: u32) -> Option<Self> {
-        let obj_ptr = unsafe { bindings::drmModeObjectGetProperties(
+        let obj_ptr = unsafe { ffi::drmModeObjectGetProperties(
             fd,
             object_id,
             object_type,
@@ -29,7 +29,7 @@ impl drmModeObjectProperties {
         property_id: u32,
         value: u64,
     ) -> Result<(), i32> {
-        let r = unsafe { bindings::drmModeObjectSetProperty(
+        let r = unsafe { ffi::drmModeObjectSetProperty(
             fd,
             object_id,
             object_type,
@@ -64,6 +64,6 @@ impl drmModeObjectProperties {
 
 impl Drop for drmModeObjectProperties {
     fn drop(&mut self) {
-	    unsafe { bindings::drmModeFreeObjectProperties(self.0); }
+	    unsafe { ffi::drmModeFreeObjectProperties(self.0); }
     }
 }
@@ -1,4 +1,4 @@
-use crate::bindings;
+use crate::{bindings, ffi};
 use core::ptr::addr_of;
 
 pub use bindings::{drmModePropertyPtr, drm_mode_property_enum};
@@ -8,7 +8,7 @@ pub struct drmModeProperty(pub(crate) drmModePropertyPtr);
 
 impl drmModeProperty {
     pub fn get(fd: i32, property_id: u32) -> Option<Self> {
-        let prop_ptr = unsafe { bindings::drmModeGetProperty(
+        let prop_ptr = unsafe { ffi::drmModeGetProperty(
             fd,
             property_id,
         ) };
@@ -92,7 +92,7 @@ impl drmModeProperty {
 
 impl Drop for drmModeProperty {
     fn drop(&mut self) {
-	    unsafe { bindings::drmModeFreeProperty(self.0); }
+	    unsafe { ffi::drmModeFreeProperty(self.0); }
     }
 }
 
@@ -1,4 +1,4 @@
-use crate::bindings;
+use crate::{bindings, ffi};
 use core::ptr::addr_of;
 
 pub use bindings::drmModePropertyBlobPtr;
@@ -8,7 +8,7 @@ pub struct drmModePropertyBlob(pub(crate) drmModePropertyBlobPtr);
 
 impl drmModePropertyBlob {
     pub fn get(fd: i32, blob_id: u32) -> Option<Self> {
-        let blob_ptr = unsafe { bindings::drmModeGetPropertyBlob(
+        let blob_ptr = unsafe { ffi::drmModeGetPropertyBlob(
             fd,
             blob_id,
         ) };
@@ -42,6 +42,6 @@ impl drmModePropertyBlob {
 
 impl Drop for drmModePropertyBlob {
     fn drop(&mut self) {
-	    unsafe { bindings::drmModeFreePropertyBlob(self.0); }
+	    unsafe { ffi::drmModeFreePropertyBlob(self.0); }
     }
 }
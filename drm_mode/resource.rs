@@ -1,4 +1,4 @@
-use crate::{bindings, drmModeConnector, drmModeCrtc};
+use crate::{bindings, ffi, drmModeConnector, drmModeCrtc};
 use core::ptr::addr_of;
 
 pub use bindings::{drmModeResPtr, drmModeObjectPropertiesPtr, drmModePropertyPtr};
@@ -8,7 +8,7 @@ pub struct drmModeRes(pub(crate) drmModeResPtr);
 
 impl drmModeRes {
     pub fn get(fd: i32) -> Option<Self> {
-        let drm_mode_res_ptr = unsafe { bindings::drmModeGetResources(fd) };
+        let drm_mode_res_ptr = unsafe { ffi::drmModeGetResources(fd) };
 
         if drm_mode_res_ptr.is_null() { return None; }
 
@@ -63,6 +63,6 @@ impl drmModeRes {
 
 impl Drop for drmModeRes {
     fn drop(&mut self) {
-	    unsafe { bindings::drmModeFreeResources(self.0); }
+	    unsafe { ffi::drmModeFreeResources(self.0); }
     }
 }
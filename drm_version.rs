@@ -12,10 +12,10 @@ pub struct drmVersion {
 #[cfg(feature = "std")]
 impl drmVersion {
     pub fn get(fd: i32) -> Result<drmVersion, i32> {
-        use crate::bindings;
+        use crate::ffi;
         use core::ffi::CStr;
 
-        let drm_ver_ptr = unsafe { bindings::drmGetVersion(fd) };
+        let drm_ver_ptr = unsafe { ffi::drmGetVersion(fd) };
 
         if drm_ver_ptr.is_null() {
             return Err(-libc::EFAULT);
@@ -27,11 +27,11 @@ impl drmVersion {
             if v.is_null() {
                 String::new()
             } else {
-                unsafe { CStr::from_ptr(v).to_str().map(|s| s.to_string()).unwrap_or_default() }
+                unsafe { CStr::from_ptr(v).to_string_lossy().into_owned() }
             }
         });
 
-        unsafe { bindings::drmFreeVersion(drm_ver_ptr) }
+        unsafe { ffi::drmFreeVersion(drm_ver_ptr) }
 
         Ok(Self {
             version_major: ver.version_major,
@@ -43,3 +43,128 @@ impl drmVersion {
         })
     }
 }
+
+#[cfg(feature = "std")]
+impl drmVersion {
+    /// (`major`, `minor`, `patchlevel`)
+    pub fn as_tuple(&self) -> (i32, i32, i32) {
+        (self.version_major, self.version_minor, self.version_patchlevel)
+    }
+
+    pub fn at_least(&self, major: i32, minor: i32) -> bool {
+        (self.version_major, self.version_minor) >= (major, minor)
+    }
+
+    /// Driver name (e.g. "amdgpu")
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Driver description (e.g. "AMD GPU")
+    pub fn desc(&self) -> &str {
+        &self.desc
+    }
+
+    /// Driver date
+    pub fn date(&self) -> &str {
+        &self.date
+    }
+
+    /// Check if the driver is amdgpu,
+    /// use with [drmVersion::get] to filter DRM nodes before [crate::AMDGPU::DeviceHandle::init].
+    pub fn is_amdgpu(&self) -> bool {
+        self.name == "amdgpu"
+    }
+
+    /*
+        Features of amdgpu KMS, ref: drivers/gpu/drm/amd/amdgpu/amdgpu_drv.c
+    */
+
+    /// 3.40.0: `AMDGPU_IDS_FLAGS_TMZ`
+    pub fn supports_tmz_flag(&self) -> bool {
+        self.at_least(3, 40)
+    }
+
+    /// 3.41.0: `AMDGPU_INFO_VIDEO_CAPS`
+    pub fn supports_video_caps(&self) -> bool {
+        self.at_least(3, 41)
+    }
+
+    /// 3.48.0: IP discovery version in `AMDGPU_INFO_HW_IP_INFO`
+    pub fn supports_ip_discovery_version(&self) -> bool {
+        self.at_least(3, 48)
+    }
+
+    /// 3.50.0: min engine/memory clock in `AMDGPU_INFO_DEV_INFO`,
+    /// `PEAK_PSTATE` clocks in `AMDGPU_INFO_SENSOR`
+    pub fn supports_min_clock_and_peak_pstate(&self) -> bool {
+        self.at_least(3, 50)
+    }
+
+    /// 3.51.0: PCIe gen and lanes in `AMDGPU_INFO_DEV_INFO`
+    pub fn supports_pcie_info(&self) -> bool {
+        self.at_least(3, 51)
+    }
+
+    /// 3.52.0: `AMDGPU_IDS_FLAGS_CONFORMANT_TRUNC_COORD`, cache sizes in `AMDGPU_INFO_DEV_INFO`
+    pub fn supports_cache_info(&self) -> bool {
+        self.at_least(3, 52)
+    }
+
+    /// 3.55.0: `AMDGPU_INFO_GPUVM_FAULT`
+    pub fn supports_gpuvm_fault(&self) -> bool {
+        self.at_least(3, 55)
+    }
+
+    /// 3.62.0: virtualization mode (`AMDGPU_IDS_FLAGS_MODE_*`)
+    pub fn supports_virtualization_mode(&self) -> bool {
+        self.at_least(3, 62)
+    }
+}
+
+/// Compares the driver name and the version numbers (`date` and `desc` are ignored)
+#[cfg(feature = "std")]
+impl PartialEq for drmVersion {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.as_tuple() == other.as_tuple()
+    }
+}
+
+/// Compares the version numbers, `None` if the driver names differ
+#[cfg(feature = "std")]
+impl PartialOrd for drmVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        if self.name != other.name { return None }
+
+        self.as_tuple().partial_cmp(&other.as_tuple())
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_drm_version_cmp() {
+    let ver = |major, minor, patchlevel| drmVersion {
+        version_major: major,
+        version_minor: minor,
+        version_patchlevel: patchlevel,
+        name: "amdgpu".to_string(),
+        date: String::new(),
+        desc: String::new(),
+    };
+
+    assert!(ver(3, 49, 0).at_least(3, 49));
+    assert!(!ver(3, 48, 9).at_least(3, 49));
+    assert!(ver(4, 0, 0).at_least(3, 49));
+    assert!(!ver(2, 60, 0).at_least(3, 49));
+    assert!(ver(3, 49, 1) > ver(3, 49, 0));
+    assert!(ver(3, 50, 0) > ver(3, 49, 99));
+    assert_eq!(ver(3, 49, 0), ver(3, 49, 0));
+
+    let radeon = drmVersion { name: "radeon".to_string(), ..ver(3, 49, 0) };
+
+    assert_ne!(radeon, ver(3, 49, 0));
+    assert_eq!(radeon.partial_cmp(&ver(3, 49, 0)), None);
+    assert!(ver(3, 41, 0).supports_video_caps());
+    assert!(!ver(3, 40, 0).supports_video_caps());
+    assert!(ver(3, 49, 0).is_amdgpu());
+}
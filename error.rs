@@ -0,0 +1,83 @@
+use core::fmt;
+
+/// Error of the query, wrapping the negated `errno` (e.g. `-EINVAL`) returned as `Err(i32)`.
+/// Queries still return `Result<T, i32>` for compatibility,
+/// convert with `.map_err(AmdgpuError::from)` to use `?` with [std::error::Error].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AmdgpuError(i32);
+
+impl AmdgpuError {
+    pub const fn new(code: i32) -> Self {
+        Self(code)
+    }
+
+    /// Raw return code (negated `errno`)
+    pub const fn code(&self) -> i32 {
+        self.0
+    }
+
+    /// Positive `errno`
+    pub const fn errno(&self) -> i32 {
+        self.0.wrapping_abs()
+    }
+
+    pub const fn message(&self) -> Option<&'static str> {
+        Some(match self.errno() {
+            libc::EINVAL => "Invalid argument",
+            libc::ENOSPC => "No space left on device",
+            libc::ENODEV => "No such device",
+            libc::EACCES => "Permission denied",
+            libc::EPERM => "Operation not permitted",
+            libc::ENOENT => "No such file or directory",
+            libc::ENOMEM => "Out of memory",
+            libc::EFAULT => "Bad address",
+            libc::EBUSY => "Device or resource busy",
+            libc::ETIMEDOUT => "Timed out",
+            libc::EOPNOTSUPP => "Operation not supported",
+            libc::ELIBACC => "Can not access a needed shared library",
+            _ => return None,
+        })
+    }
+}
+
+impl From<i32> for AmdgpuError {
+    fn from(code: i32) -> Self {
+        Self(code)
+    }
+}
+
+impl From<AmdgpuError> for i32 {
+    fn from(err: AmdgpuError) -> Self {
+        err.0
+    }
+}
+
+impl fmt::Display for AmdgpuError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.message() {
+            Some(msg) => write!(f, "{msg} (errno: {})", self.errno()),
+            None => write!(f, "Unknown error (errno: {})", self.errno()),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AmdgpuError {}
+
+#[cfg(feature = "std")]
+impl From<AmdgpuError> for std::io::Error {
+    fn from(err: AmdgpuError) -> Self {
+        std::io::Error::from_raw_os_error(err.errno())
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_amdgpu_error() {
+    let err = AmdgpuError::from(-libc::EINVAL);
+
+    assert_eq!(err.errno(), libc::EINVAL);
+    assert_eq!(i32::from(err), -libc::EINVAL);
+    assert_eq!(err.to_string(), format!("Invalid argument (errno: {})", libc::EINVAL));
+    assert_eq!(AmdgpuError::new(-9999).message(), None);
+}
@@ -308,6 +308,7 @@ fn info(pci_bus: &PCI::BUS_INFO) {
             SENSOR_TYPE::STABLE_PSTATE_GFX_MCLK,
             SENSOR_TYPE::PEAK_PSTATE_GFX_SCLK,
             SENSOR_TYPE::PEAK_PSTATE_GFX_MCLK,
+            SENSOR_TYPE::GPU_INPUT_POWER,
         ];
 
         println!("\nSensors:");
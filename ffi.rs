@@ -0,0 +1,270 @@
+//! Functions of libdrm/libdrm_amdgpu used by this crate.
+//! By default they are linked at build time (`link-drm` feature),
+//! with the `dlopen` feature they are resolved at runtime instead.
+
+// some of them are only used with the `std` feature
+#[allow(unused_imports)]
+#[cfg(not(feature = "dlopen"))]
+pub(crate) use crate::bindings::{
+    amdgpu_cs_ctx_create,
+    amdgpu_cs_ctx_free,
+    amdgpu_cs_ctx_stable_pstate,
+    amdgpu_device_deinitialize,
+    amdgpu_device_initialize,
+    amdgpu_get_marketing_name,
+    amdgpu_query_firmware_version,
+    amdgpu_query_gds_info,
+    amdgpu_query_gpu_info,
+    amdgpu_query_hw_ip_count,
+    amdgpu_query_hw_ip_info,
+    amdgpu_query_info,
+    amdgpu_query_sensor_info,
+    amdgpu_query_sw_info,
+    amdgpu_query_video_caps_info,
+    amdgpu_read_mm_registers,
+    drmCommandWrite,
+    drmFreeDevice,
+    drmFreeVersion,
+    drmGetDevice2,
+    drmGetVersion,
+    drmModeConnectorSetProperty,
+    drmModeFreeConnector,
+    drmModeFreeCrtc,
+    drmModeFreeObjectProperties,
+    drmModeFreeProperty,
+    drmModeFreePropertyBlob,
+    drmModeFreeResources,
+    drmModeGetConnector,
+    drmModeGetConnectorCurrent,
+    drmModeGetCrtc,
+    drmModeGetProperty,
+    drmModeGetPropertyBlob,
+    drmModeGetResources,
+    drmModeObjectGetProperties,
+    drmModeObjectSetProperty,
+    drmSetClientCap,
+};
+
+#[cfg(feature = "dlopen")]
+pub(crate) use dl::*;
+
+/// Whether libdrm and libdrm_amdgpu can be used.
+/// Always `true` when they are linked at build time,
+/// with the `dlopen` feature, `false` if either library failed to load.
+pub fn libdrm_amdgpu_available() -> bool {
+    #[cfg(feature = "dlopen")]
+    {
+        dl::libdrm().is_some() && dl::libdrm_amdgpu().is_some()
+    }
+    #[cfg(not(feature = "dlopen"))]
+    {
+        true
+    }
+}
+
+#[cfg(feature = "dlopen")]
+mod dl {
+    use crate::bindings::*;
+    use ::core::ffi::{c_char, c_int, c_uint, c_ulong, c_void};
+    use libloading::Library;
+    use std::sync::OnceLock;
+
+    const LIBDRM: &[&str] = &["libdrm.so.2", "libdrm.so"];
+    const LIBDRM_AMDGPU: &[&str] = &["libdrm_amdgpu.so.1", "libdrm_amdgpu.so"];
+
+    /// Returned from the functions returning `int` if the library or the symbol is missing
+    const ERR_LIB: c_int = -libc::ELIBACC;
+
+    fn load(names: &[&str]) -> Option<Library> {
+        names.iter().find_map(|name| unsafe { Library::new(name) }.ok())
+    }
+
+    pub(super) fn libdrm() -> Option<&'static Library> {
+        static LIB: OnceLock<Option<Library>> = OnceLock::new();
+
+        LIB.get_or_init(|| load(LIBDRM)).as_ref()
+    }
+
+    pub(super) fn libdrm_amdgpu() -> Option<&'static Library> {
+        static LIB: OnceLock<Option<Library>> = OnceLock::new();
+
+        LIB.get_or_init(|| load(LIBDRM_AMDGPU)).as_ref()
+    }
+
+    /// Define a function with the same signature as `bindings`,
+    /// which looks up the symbol once and returns `$missing` if it is not found.
+    macro_rules! dl_fn {
+        ($lib:ident, $missing:expr, fn $name:ident($($arg:ident: $ty:ty),* $(,)?) $(-> $ret:ty)?) => {
+            pub(crate) unsafe fn $name($($arg: $ty),*) $(-> $ret)? {
+                type F = unsafe extern "C" fn($($ty),*) $(-> $ret)?;
+                static SYM: OnceLock<Option<F>> = OnceLock::new();
+
+                let f = SYM.get_or_init(|| {
+                    let lib = $lib()?;
+                    let sym = lib.get::<F>(concat!(stringify!($name), "\0").as_bytes()).ok()?;
+
+                    Some(*sym)
+                });
+
+                match f {
+                    Some(f) => f($($arg),*),
+                    None => $missing,
+                }
+            }
+        };
+    }
+
+    dl_fn!(libdrm_amdgpu, ERR_LIB, fn amdgpu_cs_ctx_create(
+        dev: amdgpu_device_handle,
+        context: *mut amdgpu_context_handle,
+    ) -> c_int);
+    dl_fn!(libdrm_amdgpu, ERR_LIB, fn amdgpu_cs_ctx_free(context: amdgpu_context_handle) -> c_int);
+    dl_fn!(libdrm_amdgpu, ERR_LIB, fn amdgpu_cs_ctx_stable_pstate(
+        context: amdgpu_context_handle,
+        op: u32,
+        flags: u32,
+        out_flags: *mut u32,
+    ) -> c_int);
+    dl_fn!(libdrm_amdgpu, ERR_LIB, fn amdgpu_device_deinitialize(
+        device_handle: amdgpu_device_handle,
+    ) -> c_int);
+    dl_fn!(libdrm_amdgpu, ERR_LIB, fn amdgpu_device_initialize(
+        fd: c_int,
+        major_version: *mut u32,
+        minor_version: *mut u32,
+        device_handle: *mut amdgpu_device_handle,
+    ) -> c_int);
+    dl_fn!(libdrm_amdgpu, ::core::ptr::null(), fn amdgpu_get_marketing_name(
+        dev: amdgpu_device_handle,
+    ) -> *const c_char);
+    dl_fn!(libdrm_amdgpu, ERR_LIB, fn amdgpu_query_firmware_version(
+        dev: amdgpu_device_handle,
+        fw_type: c_uint,
+        ip_instance: c_uint,
+        index: c_uint,
+        version: *mut u32,
+        feature: *mut u32,
+    ) -> c_int);
+    dl_fn!(libdrm_amdgpu, ERR_LIB, fn amdgpu_query_gds_info(
+        dev: amdgpu_device_handle,
+        gds_info: *mut amdgpu_gds_resource_info,
+    ) -> c_int);
+    dl_fn!(libdrm_amdgpu, ERR_LIB, fn amdgpu_query_gpu_info(
+        dev: amdgpu_device_handle,
+        info: *mut amdgpu_gpu_info,
+    ) -> c_int);
+    dl_fn!(libdrm_amdgpu, ERR_LIB, fn amdgpu_query_hw_ip_count(
+        dev: amdgpu_device_handle,
+        type_: c_uint,
+        count: *mut u32,
+    ) -> c_int);
+    dl_fn!(libdrm_amdgpu, ERR_LIB, fn amdgpu_query_hw_ip_info(
+        dev: amdgpu_device_handle,
+        type_: c_uint,
+        ip_instance: c_uint,
+        info: *mut drm_amdgpu_info_hw_ip,
+    ) -> c_int);
+    dl_fn!(libdrm_amdgpu, ERR_LIB, fn amdgpu_query_info(
+        dev: amdgpu_device_handle,
+        info_id: c_uint,
+        size: c_uint,
+        value: *mut c_void,
+    ) -> c_int);
+    dl_fn!(libdrm_amdgpu, ERR_LIB, fn amdgpu_query_sensor_info(
+        dev: amdgpu_device_handle,
+        sensor_type: c_uint,
+        size: c_uint,
+        value: *mut c_void,
+    ) -> c_int);
+    dl_fn!(libdrm_amdgpu, ERR_LIB, fn amdgpu_query_sw_info(
+        dev: amdgpu_device_handle,
+        info: amdgpu_sw_info,
+        value: *mut c_void,
+    ) -> c_int);
+    dl_fn!(libdrm_amdgpu, ERR_LIB, fn amdgpu_query_video_caps_info(
+        dev: amdgpu_device_handle,
+        cap_type: c_uint,
+        size: c_uint,
+        value: *mut c_void,
+    ) -> c_int);
+    dl_fn!(libdrm_amdgpu, ERR_LIB, fn amdgpu_read_mm_registers(
+        dev: amdgpu_device_handle,
+        dword_offset: c_uint,
+        count: c_uint,
+        instance: u32,
+        flags: u32,
+        values: *mut u32,
+    ) -> c_int);
+
+    dl_fn!(libdrm, ERR_LIB, fn drmCommandWrite(
+        fd: c_int,
+        drmCommandIndex: c_ulong,
+        data: *mut c_void,
+        size: c_ulong,
+    ) -> c_int);
+    dl_fn!(libdrm, (), fn drmFreeDevice(device: *mut drmDevicePtr));
+    dl_fn!(libdrm, (), fn drmFreeVersion(arg1: drmVersionPtr));
+    dl_fn!(libdrm, ERR_LIB, fn drmGetDevice2(
+        fd: c_int,
+        flags: u32,
+        device: *mut drmDevicePtr,
+    ) -> c_int);
+    dl_fn!(libdrm, ::core::ptr::null_mut(), fn drmGetVersion(fd: c_int) -> drmVersionPtr);
+    dl_fn!(libdrm, ERR_LIB, fn drmModeConnectorSetProperty(
+        fd: c_int,
+        connector_id: u32,
+        property_id: u32,
+        value: u64,
+    ) -> c_int);
+    dl_fn!(libdrm, (), fn drmModeFreeConnector(ptr: drmModeConnectorPtr));
+    dl_fn!(libdrm, (), fn drmModeFreeCrtc(ptr: drmModeCrtcPtr));
+    dl_fn!(libdrm, (), fn drmModeFreeObjectProperties(ptr: drmModeObjectPropertiesPtr));
+    dl_fn!(libdrm, (), fn drmModeFreeProperty(ptr: drmModePropertyPtr));
+    dl_fn!(libdrm, (), fn drmModeFreePropertyBlob(ptr: drmModePropertyBlobPtr));
+    dl_fn!(libdrm, (), fn drmModeFreeResources(ptr: drmModeResPtr));
+    dl_fn!(libdrm, ::core::ptr::null_mut(), fn drmModeGetConnector(
+        fd: c_int,
+        connectorId: u32,
+    ) -> drmModeConnectorPtr);
+    dl_fn!(libdrm, ::core::ptr::null_mut(), fn drmModeGetConnectorCurrent(
+        fd: c_int,
+        connector_id: u32,
+    ) -> drmModeConnectorPtr);
+    dl_fn!(libdrm, ::core::ptr::null_mut(), fn drmModeGetCrtc(fd: c_int, crtcId: u32) -> drmModeCrtcPtr);
+    dl_fn!(libdrm, ::core::ptr::null_mut(), fn drmModeGetProperty(
+        fd: c_int,
+        propertyId: u32,
+    ) -> drmModePropertyPtr);
+    dl_fn!(libdrm, ::core::ptr::null_mut(), fn drmModeGetPropertyBlob(
+        fd: c_int,
+        blob_id: u32,
+    ) -> drmModePropertyBlobPtr);
+    dl_fn!(libdrm, ::core::ptr::null_mut(), fn drmModeGetResources(fd: c_int) -> drmModeResPtr);
+    dl_fn!(libdrm, ::core::ptr::null_mut(), fn drmModeObjectGetProperties(
+        fd: c_int,
+        object_id: u32,
+        object_type: u32,
+    ) -> drmModeObjectPropertiesPtr);
+    dl_fn!(libdrm, ERR_LIB, fn drmModeObjectSetProperty(
+        fd: c_int,
+        object_id: u32,
+        object_type: u32,
+        property_id: u32,
+        value: u64,
+    ) -> c_int);
+    dl_fn!(libdrm, ERR_LIB, fn drmSetClientCap(fd: c_int, capability: u64, value: u64) -> c_int);
+}
+
+#[cfg(feature = "dlopen")]
+#[test]
+fn test_dlopen_open() {
+    use crate::AMDGPU::{DeviceHandle, DeviceInitError};
+
+    let r = DeviceHandle::open("/dev/dri/nonexistent");
+
+    if libdrm_amdgpu_available() {
+        assert!(matches!(r, Err(DeviceInitError::Open(_))));
+    } else {
+        assert!(matches!(r, Err(DeviceInitError::LibraryNotFound)));
+    }
+}
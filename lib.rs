@@ -25,6 +25,11 @@ mod bindings {
     }
 }
 
+#[cfg(not(feature = "buildtime_bindgen"))]
+mod ffi;
+#[cfg(not(feature = "buildtime_bindgen"))]
+pub use ffi::libdrm_amdgpu_available;
+
 #[cfg(not(feature = "buildtime_bindgen"))]
 mod amdgpu;
 #[cfg(not(feature = "buildtime_bindgen"))]
@@ -42,6 +47,7 @@ pub mod PCI {
 #[cfg(not(feature = "buildtime_bindgen"))]
 mod drm_version;
 #[cfg(not(feature = "buildtime_bindgen"))]
+#[cfg(feature = "std")]
 pub use drm_version::*;
 
 #[cfg(not(feature = "buildtime_bindgen"))]
@@ -51,6 +57,18 @@ mod drm_mode;
 #[cfg(not(feature = "buildtime_bindgen"))]
 pub use drm_mode::*;
 
+/// Commonly used types, `use libdrm_amdgpu_sys::prelude::*;`
+#[cfg(not(feature = "buildtime_bindgen"))]
+pub mod prelude {
+    pub use crate::AMDGPU::{DeviceHandle, ASIC_NAME, CHIP_CLASS, FAMILY_NAME};
+    pub use crate::PCI::{BUS_INFO, LINK};
+    #[cfg(feature = "std")]
+    pub use crate::drmVersion;
+}
+
+mod error;
+pub use error::*;
+
 /// Convert `errno` to `Err(i32)`
 #[macro_export]
 macro_rules! query_error {
@@ -64,6 +82,17 @@ macro_rules! query_error {
 #[cfg(feature = "std")]
 use std::path::PathBuf;
 
+/// Parse all lines of DPM table, lines that fail to parse (e.g. deep sleep level) are skipped.
+#[cfg(feature = "std")]
+pub(crate) fn get_all_from_dpm<T, P: Into<PathBuf>>(
+    sysfs_path: P,
+    parse: fn(&str) -> Option<T>,
+) -> Option<Vec<T>> {
+    let s = std::fs::read_to_string(sysfs_path.into()).ok()?;
+
+    Some(s.lines().filter_map(parse).collect())
+}
+
 #[cfg(feature = "std")]
 pub(crate) fn get_min_max_from_dpm<
     T: std::cmp::Ord + std::marker::Copy,
@@ -1,5 +1,6 @@
-/// PCI information (Domain, Bus, Device, Function)
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+/// PCI information (Domain, Bus, Device, Function)  
+/// Ordered by domain, bus, device, then function.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct BUS_INFO {
     pub domain: u16,
     pub bus: u8,
@@ -11,9 +12,13 @@ pub struct BUS_INFO {
 use super::{LINK, STATUS};
 
 #[cfg(feature = "std")]
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 impl BUS_INFO {
+    pub const fn new(domain: u16, bus: u8, dev: u8, func: u8) -> Self {
+        Self { domain, bus, dev, func }
+    }
+
     pub(crate) fn drm_get_device2(
         fd: ::core::ffi::c_int,
         //  flags: u32,
@@ -82,6 +87,50 @@ impl BUS_INFO {
         self.get_drm_path("card")
     }
 
+    /// Get the index of DRM card node (`/dev/dri/cardN`)
+    #[cfg(feature = "std")]
+    pub fn get_drm_card_index(&self) -> Option<u32> {
+        Self::find_drm_node_index("/sys/class/drm", self.get_sysfs_path(), "card")
+    }
+
+    /// Get the index of DRM render node (`/dev/dri/renderDN`)
+    #[cfg(feature = "std")]
+    pub fn get_drm_render_index(&self) -> Option<u32> {
+        Self::find_drm_node_index("/sys/class/drm", self.get_sysfs_path(), "renderD")
+    }
+
+    /// Find `{prefix}N` in `drm_class_path` whose `device` links to `sysfs_path`
+    /// and is bound to the amdgpu driver, skipping connectors (`card0-DP-1`).
+    #[cfg(feature = "std")]
+    fn find_drm_node_index<P: Into<PathBuf>, Q: Into<PathBuf>>(
+        drm_class_path: P,
+        sysfs_path: Q,
+        prefix: &str,
+    ) -> Option<u32> {
+        let sysfs_path = std::fs::canonicalize(sysfs_path.into()).ok()?;
+
+        std::fs::read_dir(drm_class_path.into()).ok()?.find_map(|entry| {
+            let entry = entry.ok()?;
+            let index = Self::parse_drm_node_index(entry.file_name().to_str()?, prefix)?;
+            let device = std::fs::canonicalize(entry.path().join("device")).ok()?;
+            let driver = std::fs::read_link(device.join("driver")).ok()?;
+
+            Self::is_amdgpu_node_of(&device, &driver, &sysfs_path).then_some(index)
+        })
+    }
+
+    /// `N` of `{prefix}N`, `None` for connectors (`card0-DP-1`) and other nodes
+    #[cfg(feature = "std")]
+    fn parse_drm_node_index(name: &str, prefix: &str) -> Option<u32> {
+        name.strip_prefix(prefix)?.parse::<u32>().ok()
+    }
+
+    /// The resolved `device` of the DRM node is `sysfs_path` and its `driver` links to amdgpu
+    #[cfg(feature = "std")]
+    fn is_amdgpu_node_of(device: &Path, driver: &Path, sysfs_path: &Path) -> bool {
+        device == sysfs_path && driver.file_name().is_some_and(|name| name == "amdgpu")
+    }
+
     /// Get device debug path
     #[cfg(feature = "std")]
     pub fn get_debug_dri_path(&self) -> std::io::Result<PathBuf> {
@@ -119,12 +168,50 @@ impl BUS_INFO {
         LINK::get_max_link(&tmp)
     }
 
+    /// Get GPU current (negotiated) link speed/width from sysfs,
+    /// read from the same port as [Self::get_max_gpu_link]
+    #[cfg(feature = "std")]
+    pub fn get_current_gpu_link(&self) -> Option<LINK> {
+        let mut tmp = self.get_system_pcie_port_sysfs_path();
+
+        tmp.pop();
+
+        LINK::get_from_sysfs_with_status(tmp, STATUS::Current)
+    }
+
+    /// Check if the negotiated link width is narrower than the GPU supports
+    /// (e.g. a x16 card in a x8 slot).  
+    /// Only the width is compared, because the link speed is lowered while the GPU is idle
+    /// (DPM/ASPM) and is not a sign of a slower slot, see [Self::link_speed_is_downgraded].
+    /// A few ASICs also lower the width at the lowest `pp_dpm_pcie` level,
+    /// so check under load before warning if this returns `true`.
+    #[cfg(feature = "std")]
+    pub fn link_is_downgraded(&self) -> Option<bool> {
+        Some(self.get_current_gpu_link()?.width < self.get_max_gpu_link()?.width)
+    }
+
+    /// Check if the negotiated link speed is slower than the GPU supports.  
+    /// This is usually `true` while the GPU is idle because the link speed is lowered
+    /// by DPM/ASPM, only meaningful under load.
+    #[cfg(feature = "std")]
+    pub fn link_speed_is_downgraded(&self) -> Option<bool> {
+        Some(self.get_current_gpu_link()?.gen < self.get_max_gpu_link()?.gen)
+    }
+
     /// Get system maximum link speed/width from sysfs
     #[cfg(feature = "std")]
     pub fn get_max_system_link(&self) -> Option<LINK> {
         LINK::get_max_link(&self.get_system_pcie_port_sysfs_path())
     }
 
+    /// NUMA node of the device from `<device>/numa_node`, `None` if unknown (`-1`)
+    #[cfg(feature = "std")]
+    pub fn get_numa_node(&self) -> Option<i32> {
+        let s = std::fs::read_to_string(self.get_sysfs_path().join("numa_node")).ok()?;
+
+        s.trim_end().parse::<i32>().ok().filter(|node| *node >= 0)
+    }
+
     #[cfg(feature = "std")]
     fn from_pathbuf(path: PathBuf) -> Option<Self> {
         path
@@ -200,34 +287,93 @@ impl BUS_INFO {
 #[derive(Debug, PartialEq, Eq)]
 pub struct ParseBusInfoError;
 
+#[cfg(feature = "std")]
+impl fmt::Display for ParseBusInfoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid PCI bus info, expected `[domain:]bus:dev.func` (e.g. `0000:03:00.0`)")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseBusInfoError {}
+
+/// Parse the BDF notation, `domain:bus:dev.func` or `bus:dev.func` (domain defaults to `0000`)
 #[cfg(feature = "std")]
 impl std::str::FromStr for BUS_INFO {
     type Err = ParseBusInfoError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut split = s.split(&[':', '.']).take(4);
-        let [domain, bus, dev, func] = [split.next(), split.next(), split.next(), split.next()]
-            .map(|s| s.ok_or(ParseBusInfoError));
-        let domain = u16::from_str_radix(domain?, 16).map_err(|_| ParseBusInfoError);
-        let [bus, dev, func] = [bus, dev, func].map(|v| {
+        let (bdf, func) = s.trim().rsplit_once('.').ok_or(ParseBusInfoError)?;
+        let mut split = bdf.rsplit(':');
+        let [dev, bus] = [split.next(), split.next()].map(|s| s.ok_or(ParseBusInfoError));
+        let domain = split.next().unwrap_or("0");
+
+        if split.next().is_some() {
+            return Err(ParseBusInfoError);
+        }
+
+        let domain = u16::from_str_radix(domain, 16).map_err(|_| ParseBusInfoError);
+        let [bus, dev, func] = [bus, dev, Ok(func)].map(|v| {
             u8::from_str_radix(v?, 16).map_err(|_| ParseBusInfoError)
         });
+        let [dev, func] = [dev?, func?];
+
+        /* device: 5-bit, function: 3-bit */
+        if dev > 0x1F || func > 0x7 {
+            return Err(ParseBusInfoError);
+        }
 
         Ok(Self {
             domain: domain?,
             bus: bus?,
-            dev: dev?,
-            func: func?,
+            dev,
+            func,
         })
     }
 }
 
+#[cfg(feature = "std")]
+#[test]
+fn test_pci_bus_info_new() {
+    let bus = BUS_INFO::new(0x0, 0xd, 0x0, 0x0);
+
+    assert_eq!(bus.get_sysfs_path(), PathBuf::from("/sys/bus/pci/devices/0000:0d:00.0"));
+    assert_eq!(BUS_INFO::default(), BUS_INFO::new(0, 0, 0, 0));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_pci_bus_info_ord() {
+    let mut buses = vec![
+        BUS_INFO::new(0x1, 0x0, 0x0, 0x0),
+        BUS_INFO::new(0x0, 0xd, 0x0, 0x1),
+        BUS_INFO::new(0x0, 0x3, 0x1, 0x0),
+        BUS_INFO::new(0x0, 0xd, 0x0, 0x0),
+    ];
+
+    buses.sort();
+
+    assert_eq!(buses, [
+        BUS_INFO::new(0x0, 0x3, 0x1, 0x0),
+        BUS_INFO::new(0x0, 0xd, 0x0, 0x0),
+        BUS_INFO::new(0x0, 0xd, 0x0, 0x1),
+        BUS_INFO::new(0x1, 0x0, 0x0, 0x0),
+    ]);
+}
+
+#[cfg(feature = "std")]
 #[test]
 fn test_pci_bus_info_parse() {
     let s = "0000:0d:00.0".parse();
     let bus = BUS_INFO { domain: 0x0, bus: 0xd, dev: 0x0, func: 0x0 };
 
     assert_eq!(s, Ok(bus));
+    assert_eq!("0D:00.0".parse(), Ok(bus));
+    assert_eq!(bus.to_string().parse(), Ok(bus));
+
+    for s in ["", "0000:0d:00", "0000:0d:20.0", "0000:0d:00.8", "0:0000:0d:00.0", "0000:0d:00.0.1"] {
+        assert_eq!(s.parse::<BUS_INFO>(), Err(ParseBusInfoError), "{s}");
+    }
 }
 
 #[cfg(feature = "std")]
@@ -242,14 +388,15 @@ impl fmt::Display for BUS_INFO {
     }
 }
 
-use crate::bindings::{self, drmDevicePtr, drmFreeDevice};
+use crate::bindings::drmDevicePtr;
+use crate::ffi;
 use crate::query_error;
 use core::mem::MaybeUninit;
 
 unsafe fn __drmGetDevice2(fd: ::core::ffi::c_int, flags: u32) -> Result<drmDevicePtr, i32> {
     let mut drm_dev_info: MaybeUninit<drmDevicePtr> = MaybeUninit::uninit();
 
-    let r = bindings::drmGetDevice2(fd, flags, drm_dev_info.as_mut_ptr());
+    let r = ffi::drmGetDevice2(fd, flags, drm_dev_info.as_mut_ptr());
 
     let drm_dev_info = drm_dev_info.assume_init();
 
@@ -263,5 +410,23 @@ unsafe fn __drmGetDevice2(fd: ::core::ffi::c_int, flags: u32) -> Result<drmDevic
 }
 
 unsafe fn __drmFreeDevice(device: *mut drmDevicePtr) {
-    drmFreeDevice(device)
+    ffi::drmFreeDevice(device)
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_find_drm_node_index() {
+    assert_eq!(BUS_INFO::parse_drm_node_index("card1", "card"), Some(1));
+    assert_eq!(BUS_INFO::parse_drm_node_index("renderD129", "renderD"), Some(129));
+    assert_eq!(BUS_INFO::parse_drm_node_index("card1-DP-1", "card"), None);
+    assert_eq!(BUS_INFO::parse_drm_node_index("renderD129", "card"), None);
+
+    let amd = Path::new("/sys/devices/pci0000:00/0000:00:01.1/0000:03:00.0");
+    let other = Path::new("/sys/devices/pci0000:00/0000:00:02.0/0000:04:00.0");
+    let amdgpu = Path::new("../../../../bus/pci/drivers/amdgpu");
+    let i915 = Path::new("../../../../bus/pci/drivers/i915");
+
+    assert!(BUS_INFO::is_amdgpu_node_of(amd, amdgpu, amd));
+    assert!(!BUS_INFO::is_amdgpu_node_of(other, amdgpu, amd));
+    assert!(!BUS_INFO::is_amdgpu_node_of(amd, i915, amd));
 }
@@ -6,6 +6,7 @@ const PCIE_DPM: &str = "pp_dpm_pcie";
 
 /// PCI link speed information
 #[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LINK {
     pub gen: u8,
     pub width: u8,
@@ -15,6 +16,69 @@ pub struct LINK {
 use super::STATUS;
 
 impl LINK {
+    pub const fn new(gen: u8, width: u8) -> Self {
+        Self { gen, width }
+    }
+
+    /// PCIe generation
+    pub const fn gen(&self) -> u8 {
+        self.gen
+    }
+
+    /// Number of lanes
+    pub const fn width(&self) -> u8 {
+        self.width
+    }
+
+    /// Convert transfer rate (e.g. `"16.0"`, `"16.0GT/s"`, `"16.0 GT/s"`) to PCIe gen
+    pub fn gt_s_to_gen(gt_s: &str) -> Option<u8> {
+        let gen = match gt_s.trim_end_matches("GT/s").trim() {
+            "2.5" => 1,
+            "5.0" => 2,
+            "8.0" => 3,
+            "16.0" => 4,
+            "32.0" => 5,
+            "64.0" => 6,
+            _ => return None,
+        };
+
+        Some(gen)
+    }
+
+    /// Transfer rate per lane (MT/s) and encoding efficiency (numerator, denominator)
+    const fn rate_and_encoding(&self) -> Option<(u64, u64, u64)> {
+        let v = match self.gen {
+            1 => (2_500, 8, 10),
+            2 => (5_000, 8, 10),
+            3 => (8_000, 128, 130),
+            4 => (16_000, 128, 130),
+            5 => (32_000, 128, 130),
+            6 => (64_000, 1, 1),
+            _ => return None,
+        };
+
+        Some(v)
+    }
+
+    /// Usable bandwidth in one direction (Byte/s), after the encoding overhead.
+    /// Returns `0` for an unknown gen.
+    ///
+    /// | Gen  | Rate per lane | Encoding  |
+    /// |------|---------------|-----------|
+    /// | 1    | 2.5 GT/s      | 8b/10b    |
+    /// | 2    | 5.0 GT/s      | 8b/10b    |
+    /// | 3    | 8.0 GT/s      | 128b/130b |
+    /// | 4    | 16.0 GT/s     | 128b/130b |
+    /// | 5    | 32.0 GT/s     | 128b/130b |
+    /// | 6    | 64.0 GT/s     | 1b/1b (FLIT mode, FEC/CRC overhead is not included) |
+    ///
+    /// Packet (TLP/DLLP) overhead is not included, the actual throughput is lower.
+    pub const fn bandwidth_bytes_per_sec(&self) -> u64 {
+        let Some((rate_mt_s, num, den)) = self.rate_and_encoding() else { return 0 };
+
+        rate_mt_s * 1_000_000 * (self.width as u64) * num / den / 8
+    }
+
     #[cfg(feature = "std")]
     pub fn get_from_sysfs_with_status<P: Into<PathBuf>>(
         sysfs_path: P,
@@ -37,21 +101,11 @@ impl LINK {
     /// Convert PCIe speed str to PCIe gen
     #[cfg(feature = "std")]
     pub fn speed_to_gen(speed: &str) -> Option<u8> {
-        let gen = match speed {
-            "2.5 GT/s PCIe" => 1,
-            "5.0 GT/s PCIe" => 2,
-            "8.0 GT/s PCIe" => 3,
-            "16.0 GT/s PCIe" => 4,
-            "32.0 GT/s PCIe" => 5,
-            "64.0 GT/s PCIe" => 6,
-            _ => return None,
-        };
-
-        Some(gen)
+        Self::gt_s_to_gen(speed.strip_suffix(" GT/s PCIe")?)
     }
 
     #[cfg(feature = "std")]
-    fn parse_dpm_line(s: &str) -> Option<Self> {
+    pub(crate) fn parse_dpm_line(s: &str) -> Option<Self> {
         let mut gen: Option<u8> = None;
         let mut width: Option<u8> = None;
 
@@ -59,27 +113,15 @@ impl LINK {
             if tmp.ends_with("GT/s") {
                 // "0: 2.5GT/s"
                 let Some(pos) = tmp.find(' ') else { continue };
-                gen = {
-                    let tmp = match tmp.get(pos+1..)? {
-                        "2.5GT/s" => 1,
-                        "5.0GT/s" => 2,
-                        "8.0GT/s" => 3,
-                        "16.0GT/s" => 4,
-                        "32.0GT/s" => 5,
-                        "64.0GT/s" => 6,
-                        _ => 0,
-                    };
-
-                    (tmp != 0).then_some(tmp)
-                };
+                gen = Self::gt_s_to_gen(tmp.get(pos+1..)?);
                 continue;
             }
 
             if tmp.starts_with('x') {
-                // "x8 ", "x16 * "
+                // "x8 ", "x16 * ", "x16 619Mhz *"
                 let tmp = tmp.trim_start_matches('x');
-                let Some(space_pos) = tmp.find(' ') else { continue };
-                width = tmp.get(..space_pos)?.parse().ok();
+                let Some(w) = tmp.split_whitespace().next() else { continue };
+                width = w.parse().ok();
                 continue;
             }
         }
@@ -118,3 +160,41 @@ impl LINK {
         Some(Self { gen, width })
     }
 }
+
+/// e.g. "PCIe 4.0 x16"
+impl core::fmt::Display for LINK {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "PCIe {}.0 x{}", self.gen, self.width)
+    }
+}
+
+#[test]
+fn test_link_gt_s_to_gen() {
+    assert_eq!(LINK::gt_s_to_gen("2.5"), Some(1));
+    assert_eq!(LINK::gt_s_to_gen("5.0GT/s"), Some(2));
+    assert_eq!(LINK::gt_s_to_gen("8.0 GT/s"), Some(3));
+    assert_eq!(LINK::gt_s_to_gen("16.0"), Some(4));
+    assert_eq!(LINK::gt_s_to_gen("32.0GT/s"), Some(5));
+    assert_eq!(LINK::gt_s_to_gen("64.0 GT/s"), Some(6));
+    assert_eq!(LINK::gt_s_to_gen("128.0"), None);
+
+    let link = LINK { gen: 4, width: 16 };
+
+    assert_eq!((link.gen(), link.width()), (4, 16));
+}
+
+#[test]
+fn test_link_bandwidth() {
+    assert_eq!(LINK::new(1, 1).bandwidth_bytes_per_sec(), 250_000_000);
+    assert_eq!(LINK::new(3, 16).bandwidth_bytes_per_sec(), 15_753_846_153);
+    assert_eq!(LINK::new(4, 16).bandwidth_bytes_per_sec(), 31_507_692_307);
+    assert_eq!(LINK::new(0, 16).bandwidth_bytes_per_sec(), 0);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_link_display() {
+    assert_eq!(LINK::speed_to_gen("32.0 GT/s PCIe"), Some(5));
+    assert_eq!(LINK { gen: 4, width: 16 }.to_string(), "PCIe 4.0 x16");
+    assert_eq!(LINK::parse_dpm_line("2: 64.0GT/s, x8 *"), Some(LINK { gen: 6, width: 8 }));
+}
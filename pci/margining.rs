@@ -0,0 +1,87 @@
+use super::BUS_INFO;
+use std::path::PathBuf;
+
+/// Link integrity counters of the PCIe device, for troubleshooting unstable links.
+/// The kernel does not expose the results of Lane Margining at the Receiver (PCIe 4.0+) in sysfs,
+/// use `pcilmr` from pciutils to run the margining itself.
+/// `pcie_replay_count` requires the amdgpu driver, and `aer_dev_correctable` requires
+/// `CONFIG_PCIEAER` and a platform that grants AER control to the OS.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PcieMargining {
+    /// `pcie_replay_count`, the number of TLP replays
+    pub replay_count: Option<u64>,
+    /// `aer_dev_correctable`, (error name, count)
+    pub correctable_errors: Vec<(String, u64)>,
+    /// `TOTAL_ERR_COR` of `aer_dev_correctable`
+    pub total_correctable_errors: Option<u64>,
+}
+
+impl PcieMargining {
+    /// Returns `None` if neither `pcie_replay_count` nor `aer_dev_correctable` is available
+    pub fn get_from_sysfs<P: Into<PathBuf>>(sysfs_path: P) -> Option<Self> {
+        let path = sysfs_path.into();
+        let replay = std::fs::read_to_string(path.join("pcie_replay_count")).ok();
+        let aer = std::fs::read_to_string(path.join("aer_dev_correctable")).ok();
+
+        if replay.is_none() && aer.is_none() { return None }
+
+        Some(Self::parse(replay.as_deref(), aer.as_deref()))
+    }
+
+    /*
+        aer_dev_correctable:
+        RxErr 0
+        BadTLP 0
+        ...
+        TOTAL_ERR_COR 0
+    */
+    pub fn parse(replay_count: Option<&str>, aer_dev_correctable: Option<&str>) -> Self {
+        let replay_count = replay_count.and_then(|s| s.trim().parse().ok());
+        let mut correctable_errors = Vec::new();
+        let mut total_correctable_errors = None;
+
+        for line in aer_dev_correctable.unwrap_or_default().lines() {
+            let mut split = line.split_whitespace();
+            let [Some(name), Some(count)] = [split.next(), split.next()] else { continue };
+            let Ok(count) = count.parse::<u64>() else { continue };
+
+            if name == "TOTAL_ERR_COR" {
+                total_correctable_errors = Some(count);
+            } else {
+                correctable_errors.push((name.to_string(), count));
+            }
+        }
+
+        Self { replay_count, correctable_errors, total_correctable_errors }
+    }
+
+    pub fn has_errors(&self) -> bool {
+        self.replay_count.is_some_and(|c| c != 0)
+            || self.total_correctable_errors.is_some_and(|c| c != 0)
+            || self.correctable_errors.iter().any(|(_, c)| *c != 0)
+    }
+}
+
+impl BUS_INFO {
+    pub fn get_pcie_margining(&self) -> Option<PcieMargining> {
+        PcieMargining::get_from_sysfs(self.get_sysfs_path())
+    }
+}
+
+#[test]
+fn test_pcie_margining_parse() {
+    let aer = "RxErr 2\nBadTLP 0\nBadDLLP 1\nRollover 0\nTimeout 0\nNonFatalErr 0\n\
+        CorrIntErr 0\nHeaderOF 0\nTOTAL_ERR_COR 3\n";
+    let m = PcieMargining::parse(Some("5\n"), Some(aer));
+
+    assert_eq!(m.replay_count, Some(5));
+    assert_eq!(m.total_correctable_errors, Some(3));
+    assert_eq!(m.correctable_errors.len(), 8);
+    assert_eq!(m.correctable_errors[0], ("RxErr".to_string(), 2));
+    assert!(m.has_errors());
+
+    let m = PcieMargining::parse(Some("0\n"), None);
+
+    assert!(m.correctable_errors.is_empty());
+    assert!(!m.has_errors());
+}
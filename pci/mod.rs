@@ -6,3 +6,8 @@ pub use status::*;
 
 mod link;
 pub use link::*;
+
+#[cfg(feature = "std")]
+mod margining;
+#[cfg(feature = "std")]
+pub use margining::*;